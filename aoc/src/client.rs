@@ -0,0 +1,157 @@
+//! Fetching puzzle inputs from and submitting answers to the Advent of Code site, or anything
+//! that speaks the same protocol at another base URL
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use aoc_common::{Error, Result};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2018;
+
+const USER_AGENT: &str = "github.com/enjmusic/aoc_2018 runner";
+
+/// What the server said about a submitted answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    /// With the server's hint, e.g. "your answer is too high", if it gave one
+    Wrong { hint: Option<String> },
+    /// With how long is left to wait, e.g. "34s", if the server said
+    TooSoon { wait: Option<String> },
+    /// The part was already solved, or isn't unlocked yet
+    WrongLevel,
+    /// A response none of the above could be recognised in, as text
+    Unrecognised(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "That's the right answer!"),
+            Verdict::Wrong { hint: Some(hint) } => write!(f, "That's not the right answer; {}", hint),
+            Verdict::Wrong { hint: None } => write!(f, "That's not the right answer"),
+            Verdict::TooSoon { wait: Some(wait) } => write!(f, "Answered too recently, {} left to wait", wait),
+            Verdict::TooSoon { wait: None } => write!(f, "Answered too recently"),
+            Verdict::WrongLevel => write!(f, "That part is already solved or not unlocked yet"),
+            Verdict::Unrecognised(text) => write!(f, "Unrecognised response: {}", text),
+        }
+    }
+}
+
+/// The text of an HTML page's `<article>`, or the whole page if it has none, with tags removed
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Works out the verdict from the page the server returns after submitting an answer
+pub fn parse_verdict(html: &str) -> Verdict {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Verdict::Right
+    } else if text.contains("That's not the right answer") {
+        let hint = ["too high", "too low"].iter().find(|h| text.contains(*h)).map(|h| format!("your answer is {}", h));
+        Verdict::Wrong { hint }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text.split("You have ").nth(1)
+            .and_then(|rest| rest.split(" left to wait").next())
+            .map(str::to_string);
+        Verdict::TooSoon { wait }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unrecognised(text)
+    }
+}
+
+/// Whether there's already an input at `path`. An empty file, like the one `aoc new` creates,
+/// doesn't count.
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).map(|m| m.len() > 0).unwrap_or(false)
+}
+
+/// Whether `fetch_input` downloaded the input or found it already on disk
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// A client using the session token stored in `path`
+    pub fn from_session_file(base_url: &str, path: &Path) -> Result<Client> {
+        let session = fs::read_to_string(path).map_err(|source| Error::Io { file: Some(path.to_path_buf()), source })?;
+        if session.trim().is_empty() {
+            return Err(Error::Other(format!("{}: session token file is empty", path.display())));
+        }
+        Ok(Client::new(base_url, &session))
+    }
+
+    fn day_url(&self, day: usize) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn send(&self, request: ureq::Request, form: Option<&[(&str, &str)]>) -> Result<String> {
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let url = request.url().to_string();
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(Error::Other(format!("{} returned {}: {}", url, code, body.trim())))
+            },
+            Err(e) => Err(Error::Other(format!("Couldn't reach {}: {}", url, e))),
+        }
+    }
+
+    /// Saves the day's input to `path`, unless it's already there
+    pub fn fetch_input(&self, day: usize, path: &Path) -> Result<Fetched> {
+        if is_cached(path) {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.send(self.agent.get(&format!("{}/input", self.day_url(day))), None)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| Error::Io { file: Some(dir.to_path_buf()), source })?;
+        }
+        fs::write(path, input).map_err(|source| Error::Io { file: Some(path.to_path_buf()), source })?;
+        Ok(Fetched::Downloaded)
+    }
+
+    pub fn submit(&self, day: usize, part: u8, answer: &str) -> Result<Verdict> {
+        let level = part.to_string();
+        let page = self.send(self.agent.post(&format!("{}/answer", self.day_url(day))), Some(&[("level", &level), ("answer", answer)]))?;
+        Ok(parse_verdict(&page))
+    }
+}
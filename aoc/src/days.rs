@@ -0,0 +1,24 @@
+use aoc_common::RunFn;
+use aoc_common::gen::Generator;
+
+/// Every implemented day, in order. `DAYS[n - 1]` runs day `n`.
+pub const DAYS: &[RunFn] = &[
+    aoc_common::run::<aoc_1::Day1>,
+    aoc_common::run::<aoc_2::Day2>,
+    aoc_common::run::<aoc_3::Day3>,
+    aoc_common::run::<aoc_4::Day4>,
+    aoc_common::run::<aoc_5::Day5>,
+    aoc_common::run::<aoc_6::Day6>,
+    aoc_common::run::<aoc_7::Day7>,
+];
+
+/// Every implemented day's input generator, in the same order as `DAYS`
+pub const GENERATORS: &[Generator] = &[
+    aoc_1::gen::GENERATOR,
+    aoc_2::gen::GENERATOR,
+    aoc_3::gen::GENERATOR,
+    aoc_4::gen::GENERATOR,
+    aoc_5::gen::GENERATOR,
+    aoc_6::gen::GENERATOR,
+    aoc_7::gen::GENERATOR,
+];
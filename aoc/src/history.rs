@@ -0,0 +1,67 @@
+//! `aoc history`: shows how each part's answers and timings changed over the recorded runs,
+//! flagging changed answers and slowdowns
+
+use std::path::Path;
+use std::time::Duration;
+use aoc_common::history::{self, Entry};
+use aoc_common::Result;
+
+/// A time, with how much it changed from the previous run's
+fn format_time(time: u64, previous: Option<u64>) -> String {
+    match previous.and_then(|p| history::percent_change(time, p)) {
+        Some(change) => format!("{:?} ({:+.0}%)", Duration::from_nanos(time), change),
+        None => format!("{:?}", Duration::from_nanos(time)),
+    }
+}
+
+fn print_entry(entry: &Entry) {
+    let record = entry.record;
+    let flags = entry.flags.iter().map(|f| f.describe()).collect::<Vec<_>>();
+    let flags = if flags.is_empty() { String::new() } else { format!("  [{}]", flags.join("; ")) };
+    let line = format!(
+        "  {:<9} {:>16}  parse {:<22} part {:<22}{}",
+        record.commit,
+        record.answer,
+        format_time(record.parse_ns, entry.previous.map(|p| p.parse_ns)),
+        format_time(record.part_ns, entry.previous.map(|p| p.part_ns)),
+        flags,
+    );
+    println!("{}", line.trim_end());
+}
+
+/// Prints the runs of each part on each input in order, optionally only the flagged ones or
+/// those of one day or part
+pub fn show(path: &Path, day: Option<u8>, part: Option<u8>, threshold: f64, flagged_only: bool) -> Result<()> {
+    let records = history::load(path)?;
+    let entries = history::analyse(&records, threshold);
+    let entries = entries.iter()
+        .filter(|e| day.is_none_or(|d| d == e.record.day) && part.is_none_or(|p| p == e.record.part))
+        .collect::<Vec<&Entry>>();
+
+    // Group the runs of each part on each input, keeping the groups in the order they were first run
+    let mut series: Vec<Vec<&Entry>> = vec![];
+    for entry in entries {
+        match series.iter_mut().find(|s| s[0].record.same_series(entry.record)) {
+            Some(s) => s.push(entry),
+            None => series.push(vec![entry]),
+        }
+    }
+    series.sort_by_key(|s| (s[0].record.day, s[0].record.part));
+
+    let mut flagged = 0;
+    for s in &series {
+        let shown = s.iter().filter(|e| !flagged_only || !e.flags.is_empty()).collect::<Vec<_>>();
+        flagged += s.iter().filter(|e| !e.flags.is_empty()).count();
+        if shown.is_empty() { continue }
+        let first = s[0].record;
+        let kind = if first.benchmarked { ", benchmarked" } else { "" };
+        println!("== Day {} part {} on {} ({}, {}{})", first.day, first.part, first.input, first.input_hash, first.profile, kind);
+        for entry in shown { print_entry(entry); }
+    }
+    if series.is_empty() {
+        println!("No runs recorded in {}", path.display());
+    } else {
+        println!("{} flagged run{}", flagged, if flagged == 1 { "" } else { "s" });
+    }
+    Ok(())
+}
@@ -0,0 +1,245 @@
+//! `aoc leaderboard`: reports on a private leaderboard's JSON export, with star counts, how long
+//! each member took to get from part 1 to part 2, and local score rankings, alongside our own
+//! benchmark timings
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use serde_json::{json, Value};
+use aoc_common::{Error, Result};
+
+/// One member of the leaderboard and when they got each star
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: String,
+    /// Anonymous members get a name made from their id, like the site shows them
+    pub name: String,
+    /// Unix timestamps of parts 1 and 2, by day
+    pub stars: BTreeMap<u32, [Option<u64>; 2]>,
+}
+
+impl Member {
+    pub fn star_count(&self) -> usize {
+        self.stars.values().flatten().filter(|ts| ts.is_some()).count()
+    }
+
+    /// How long the member took to get from part 1 to part 2 of `day`, if they finished both
+    pub fn delta(&self, day: u32) -> Option<Duration> {
+        match self.stars.get(&day)? {
+            [Some(part1), Some(part2)] => Some(Duration::from_secs(part2.saturating_sub(*part1))),
+            _ => None,
+        }
+    }
+}
+
+/// A member's place in the local score rankings
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Standing<'a> {
+    /// 1-based. Members with the same score and stars share a rank.
+    pub rank: usize,
+    pub member: &'a Member,
+    pub stars: usize,
+    pub local_score: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Leaderboard {
+    /// Sorted by id
+    pub members: Vec<Member>,
+}
+
+/// A star timestamp, which older exports give as a string and newer ones as a number
+fn timestamp(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn parse_member(id: &str, value: &Value) -> Result<Member> {
+    let name = match &value["name"] {
+        Value::String(name) => name.clone(),
+        Value::Null => format!("(anonymous user #{})", id),
+        other => return Err(From::from(format!("Member {} has a name that isn't a string: {}", id, other))),
+    };
+    let mut stars = BTreeMap::new();
+    if let Some(days) = value["completion_day_level"].as_object() {
+        for (day, parts) in days {
+            let day = day.parse::<u32>().map_err(|_| format!("Member {} has stars for a day called {:?}", id, day))?;
+            let mut times = [None, None];
+            for (part, time) in times.iter_mut().enumerate() {
+                let star = &parts[(part + 1).to_string()];
+                if star.is_null() { continue }
+                *time = Some(timestamp(&star["get_star_ts"])
+                    .ok_or_else(|| format!("Member {} has a day {} part {} star with no valid get_star_ts", id, day, part + 1))?);
+            }
+            stars.insert(day, times);
+        }
+    }
+    Ok(Member { id: id.to_string(), name, stars })
+}
+
+impl Leaderboard {
+    /// Reads the JSON the site serves for a private leaderboard
+    pub fn parse(json: &str) -> Result<Leaderboard> {
+        let value: Value = serde_json::from_str(json).map_err(|e| format!("Not valid JSON: {}", e))?;
+        let members = value["members"].as_object().ok_or("There's no members object")?;
+        let mut members = members.iter().map(|(id, member)| parse_member(id, member)).collect::<Result<Vec<Member>>>()?;
+        members.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(Leaderboard { members })
+    }
+
+    pub fn load(path: &Path) -> Result<Leaderboard> {
+        let json = fs::read_to_string(path).map_err(|source| Error::Io { file: Some(path.to_path_buf()), source })?;
+        Leaderboard::parse(&json).map_err(|e| Error::Other(format!("{}: {}", path.display(), e)))
+    }
+
+    /// The member whose name or id is `who`
+    pub fn find(&self, who: &str) -> Option<&Member> {
+        self.members.iter().find(|m| m.name == who || m.id == who)
+    }
+
+    /// Each member's local score by id, worked out from the star times: for each star, the first
+    /// of N members to get it scores N points, the second N - 1 and so on
+    pub fn local_scores(&self) -> HashMap<&str, u64> {
+        let mut scores = self.members.iter().map(|m| (m.id.as_str(), 0)).collect::<HashMap<&str, u64>>();
+        let days = self.members.iter().flat_map(|m| m.stars.keys().copied()).collect::<BTreeSet<u32>>();
+        for day in days {
+            for part in 0..2 {
+                let mut finishers = self.members.iter()
+                    .filter_map(|m| m.stars.get(&day).and_then(|s| s[part]).map(|ts| (ts, m.id.as_str())))
+                    .collect::<Vec<(u64, &str)>>();
+                finishers.sort();
+                for (position, (_, id)) in finishers.into_iter().enumerate() {
+                    *scores.get_mut(id).unwrap() += (self.members.len() - position) as u64;
+                }
+            }
+        }
+        scores
+    }
+
+    /// Every member from the highest local score to the lowest, then by stars, then by id
+    pub fn standings(&self) -> Vec<Standing<'_>> {
+        let scores = self.local_scores();
+        let mut standings = self.members.iter()
+            .map(|member| Standing { rank: 0, member, stars: member.star_count(), local_score: scores[member.id.as_str()] })
+            .collect::<Vec<Standing>>();
+        standings.sort_by(|a, b| (b.local_score, b.stars).cmp(&(a.local_score, a.stars)).then(a.member.id.cmp(&b.member.id)));
+        for i in 0..standings.len() {
+            let tied = i > 0 && (standings[i - 1].local_score, standings[i - 1].stars) == (standings[i].local_score, standings[i].stars);
+            standings[i].rank = if tied { standings[i - 1].rank } else { i + 1 };
+        }
+        standings
+    }
+}
+
+/// Our median benchmark times for one day, as recorded with `--bench-csv`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DayTimings {
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+/// Benchmark times by day
+pub type Timings = BTreeMap<u32, DayTimings>;
+
+/// Reads a CSV written by `--bench-csv`. Rows are appended as benchmarks are run, so the last row
+/// for each day and part wins.
+pub fn parse_timings(csv: &str) -> Result<Timings> {
+    let mut lines = csv.lines().enumerate();
+    let header = lines.next().map(|(_, h)| h.split(',').collect::<Vec<&str>>()).unwrap_or_default();
+    let column = |name: &str| header.iter().position(|h| *h == name).ok_or_else(|| format!("The CSV has no {} column", name));
+    let (day_col, phase_col, median_col) = (column("day")?, column("phase")?, column("median_ns")?);
+
+    let mut timings = Timings::new();
+    for (idx, line) in lines {
+        if line.trim().is_empty() { continue }
+        let fields = line.split(',').collect::<Vec<&str>>();
+        let field = |col: usize| fields.get(col).copied().ok_or_else(|| Error::parse(idx + 1, line, "Too few columns"));
+        let day = field(day_col)?.parse::<u32>().map_err(|_| Error::parse(idx + 1, line, "Invalid day"))?;
+        let median = field(median_col)?.parse::<u64>().map_err(|_| Error::parse(idx + 1, line, "Invalid median_ns"))?;
+        let entry = timings.entry(day).or_default();
+        match field(phase_col)? {
+            "part1" => entry.part1 = Some(Duration::from_nanos(median)),
+            "part2" => entry.part2 = Some(Duration::from_nanos(median)),
+            _ => (),
+        }
+    }
+    Ok(timings)
+}
+
+pub fn load_timings(path: &Path) -> Result<Timings> {
+    let csv = fs::read_to_string(path).map_err(|source| Error::Io { file: Some(path.to_path_buf()), source })?;
+    parse_timings(&csv).map_err(|e| e.in_file(path))
+}
+
+/// A time between stars, like the site shows them, e.g. "1:02:03"
+pub fn format_delta(delta: Duration) -> String {
+    let secs = delta.as_secs();
+    format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+/// One JSON object per member, in ranking order, with our timings for the members in `timings`
+/// (keyed by id)
+pub fn report_json(leaderboard: &Leaderboard, timings: &HashMap<String, Timings>) -> Vec<Value> {
+    leaderboard.standings().iter().map(|standing| {
+        let member = standing.member;
+        let own = timings.get(&member.id);
+        let days = member.stars.iter().map(|(&day, &[part1, part2])| {
+            let mut obj = json!({
+                "day": day,
+                "part1_ts": part1,
+                "part2_ts": part2,
+                "delta_s": member.delta(day).map(|d| d.as_secs()),
+            });
+            if let Some(own) = own {
+                let day_timings = own.get(&day).copied().unwrap_or_default();
+                obj["part1_ns"] = json!(day_timings.part1.map(|t| t.as_nanos() as u64));
+                obj["part2_ns"] = json!(day_timings.part2.map(|t| t.as_nanos() as u64));
+            }
+            obj
+        }).collect::<Vec<Value>>();
+        json!({
+            "rank": standing.rank,
+            "id": member.id,
+            "name": member.name,
+            "stars": standing.stars,
+            "local_score": standing.local_score,
+            "days": days,
+        })
+    }).collect()
+}
+
+/// The rankings, then each member's time from part 1 to part 2 of each day they got both stars
+/// for, with our timings for the members in `timings` (keyed by id)
+pub fn report_text(leaderboard: &Leaderboard, timings: &HashMap<String, Timings>) -> String {
+    let standings = leaderboard.standings();
+    let width = standings.iter().map(|s| s.member.name.len()).max().unwrap_or(0).max("Member".len());
+    let mut out = format!("{:>4}  {:>5}  {:>5}  Member\n", "Rank", "Score", "Stars");
+    for s in &standings {
+        out += &format!("{:>4}  {:>5}  {:>5}  {}\n", s.rank, s.local_score, s.stars, s.member.name);
+    }
+
+    out += &format!("\n{:>3}  {:<width$}  {:>10}", "Day", "Member", "Part 1 → 2", width = width);
+    if !timings.is_empty() { out += &format!("  {:>12}  {:>12}", "Our part 1", "Our part 2"); }
+    out += "\n";
+    let days = leaderboard.members.iter().flat_map(|m| m.stars.keys().copied()).collect::<BTreeSet<u32>>();
+    for day in days {
+        for s in &standings {
+            let delta = match s.member.delta(day) {
+                Some(delta) => delta,
+                None => continue,
+            };
+            out += &format!("{:>3}  {:<width$}  {:>10}", day, s.member.name, format_delta(delta), width = width);
+            if let Some(own) = timings.get(&s.member.id) {
+                let day_timings = own.get(&day).copied().unwrap_or_default();
+                let time = |t: Option<Duration>| t.map(|t| format!("{:?}", t)).unwrap_or_else(|| "-".to_string());
+                out += &format!("  {:>12}  {:>12}", time(day_timings.part1), time(day_timings.part2));
+            }
+            out += "\n";
+        }
+    }
+    out
+}
@@ -0,0 +1,7 @@
+//! The parts of the runner that are tested on their own
+
+pub mod client;
+pub mod leaderboard;
+pub mod new;
+pub mod reach;
+pub mod track;
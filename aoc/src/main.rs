@@ -0,0 +1,308 @@
+mod days;
+mod history;
+mod watch;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+use aoc::client::{self, Client, Fetched, Verdict};
+use aoc::leaderboard::{self, Leaderboard};
+use aoc::{new, reach, track};
+use aoc_1::{Day1, Width};
+use aoc_common::{gen, parse_part, Error, Format, Result, RunOptions, Solution};
+use days::{DAYS, GENERATORS};
+
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Runs Advent of Code 2018 solutions")]
+enum Cli {
+    /// Run one day's solution, or every day's with --all
+    Run {
+        #[structopt(short = "d", long = "day", required_unless = "all")]
+        day: Option<usize>,
+        /// Input file, or - for stdin. Can be given more than once. Defaults to aoc_<day>/input/in.txt
+        #[structopt(short = "f", long = "input", parse(from_os_str), number_of_values = 1, conflicts_with = "all")]
+        inputs: Vec<PathBuf>,
+        /// Run every day in order
+        #[structopt(long = "all", conflicts_with = "day")]
+        all: bool,
+        #[structopt(flatten)]
+        opts: RunOptions,
+    },
+    /// Rebuild and re-run a day whenever its source or input changes
+    Watch {
+        #[structopt(short = "d", long = "day")]
+        day: usize,
+        /// Defaults to aoc_<day>/input/in.txt
+        #[structopt(short = "f", long = "input", parse(from_os_str))]
+        input: Option<PathBuf>,
+        /// Build and run in release mode, for more meaningful timings
+        #[structopt(long = "release")]
+        release: bool,
+    },
+    /// Create the crate for a new day and add it to the workspace and runner
+    New {
+        #[structopt(short = "d", long = "day")]
+        day: usize,
+    },
+    /// Download a day's input into aoc_<day>/input/in.txt, unless it's already there
+    Fetch {
+        #[structopt(short = "d", long = "day")]
+        day: usize,
+        #[structopt(flatten)]
+        server: Server,
+    },
+    /// Submit the answer to one part of a day
+    Submit {
+        #[structopt(short = "d", long = "day")]
+        day: usize,
+        #[structopt(short = "p", long = "part", parse(try_from_str = parse_part))]
+        part: u8,
+        #[structopt(short = "a", long = "answer")]
+        answer: String,
+        #[structopt(flatten)]
+        server: Server,
+    },
+    /// Generate a random input for a day
+    Gen {
+        #[structopt(short = "d", long = "day")]
+        day: usize,
+        /// How big an input to generate, in the day's own units. Defaults to about the size of the real input
+        #[structopt(short = "n", long = "size")]
+        size: Option<usize>,
+        /// Seed for the random generator, picked at random and printed to stderr if not given
+        #[structopt(long = "seed")]
+        seed: Option<u64>,
+        /// Write the input here instead of stdout
+        #[structopt(short = "o", long = "output", parse(from_os_str))]
+        output: Option<PathBuf>,
+    },
+    /// Report star counts, part 1 to part 2 times and local score rankings from a private leaderboard's JSON export
+    Leaderboard {
+        /// The JSON export, saved from the leaderboard's API link
+        #[structopt(short = "f", long = "input", parse(from_os_str))]
+        input: PathBuf,
+        /// A member's name or id and a CSV written by --bench-csv, as NAME=FILE, to show our timings
+        /// next to theirs. Can be given more than once.
+        #[structopt(long = "timings", parse(try_from_str = parse_timings_arg), number_of_values = 1)]
+        timings: Vec<(String, PathBuf)>,
+        /// Print a table, or one JSON object per member
+        #[structopt(long = "format", default_value = "text", possible_values = &["text", "json"])]
+        format: Format,
+    },
+    /// Follow day 1's frequency through a never-ending stream of changes, reporting the first repeat
+    Track {
+        /// Read the changes from this file instead of stdin
+        #[structopt(short = "f", long = "input", parse(from_os_str), conflicts_with = "connect")]
+        input: Option<PathBuf>,
+        /// Read the changes from a TCP connection to this address instead, e.g. 127.0.0.1:4000
+        #[structopt(long = "connect")]
+        connect: Option<String>,
+        /// Keep applying changes after the first repeat, and print the frequency once the stream ends
+        #[structopt(long = "keep-going")]
+        keep_going: bool,
+        /// Keep frequencies in 128-bit integers
+        #[structopt(long = "wide")]
+        wide: bool,
+    },
+    /// Find when day 1's frequency first reaches each target, or list the frequencies it ever reaches
+    Reach {
+        /// Defaults to aoc_1/input/in.txt
+        #[structopt(short = "f", long = "input", parse(from_os_str))]
+        input: Option<PathBuf>,
+        /// A frequency to find the first time it's reached. Can be given more than once; without
+        /// any, every reachable frequency is listed.
+        #[structopt(short = "t", long = "target", number_of_values = 1, allow_hyphen_values = true)]
+        targets: Vec<i128>,
+        /// Keep frequencies in 128-bit integers
+        #[structopt(long = "wide")]
+        wide: bool,
+        /// Parse the input exactly as it is, as with run --raw
+        #[structopt(long = "raw")]
+        raw: bool,
+    },
+    /// Show how answers and timings changed over the recorded runs, flagging changed answers and slowdowns
+    History {
+        #[structopt(short = "d", long = "day")]
+        day: Option<u8>,
+        #[structopt(short = "p", long = "part", parse(try_from_str = parse_part))]
+        part: Option<u8>,
+        /// Flag a run whose parse or part time is more than this percent slower than the previous run's
+        #[structopt(long = "threshold", default_value = "20")]
+        threshold: f64,
+        /// Only show the flagged runs
+        #[structopt(long = "flagged")]
+        flagged: bool,
+        /// Defaults to .history.jsonl in the workspace root
+        #[structopt(long = "history", parse(from_os_str))]
+        file: Option<PathBuf>,
+    },
+}
+
+// Where to fetch inputs from and submit answers to (see RunOptions on why this isn't a doc comment)
+#[derive(Debug, StructOpt)]
+struct Server {
+    /// Where the puzzles are served from
+    #[structopt(long = "base-url", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+    /// File holding the value of the site's session cookie. Defaults to .session in the workspace root
+    #[structopt(long = "session-file", parse(from_os_str))]
+    session_file: Option<PathBuf>,
+}
+
+impl Server {
+    fn client(&self) -> Result<Client> {
+        let path = self.session_file.clone().unwrap_or_else(|| workspace_root().join(".session"));
+        Client::from_session_file(&self.base_url, &path)
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn default_input(day: usize) -> PathBuf {
+    workspace_root().join(format!("aoc_{}", day)).join("input").join("in.txt")
+}
+
+fn fetch_day(day: usize, server: &Server) -> Result<()> {
+    if !workspace_root().join(format!("aoc_{}", day)).exists() {
+        return Err(From::from(format!("There's no aoc_{} yet, create it with `aoc new --day {}` first", day, day)));
+    }
+    let path = default_input(day);
+    // Don't insist on a session token when there's nothing to download
+    let fetched = if client::is_cached(&path) { Fetched::Cached } else { server.client()?.fetch_input(day, &path)? };
+    match fetched {
+        Fetched::Downloaded => println!("Saved day {}'s input to {}", day, path.display()),
+        Fetched::Cached => println!("Day {}'s input is already in {}", day, path.display()),
+    }
+    Ok(())
+}
+
+fn run_day(day: usize, mut inputs: Vec<PathBuf>, opts: &RunOptions) -> Result<()> {
+    let run = DAYS.get(day.wrapping_sub(1))
+        .ok_or_else(|| format!("Day {} is not implemented (expected 1-{})", day, DAYS.len()))?;
+    if inputs.is_empty() { inputs.push(default_input(day)); }
+    aoc_common::run_inputs(*run, &inputs, opts)
+}
+
+fn gen_day(day: usize, size: Option<usize>, seed: Option<u64>, output: Option<PathBuf>) -> Result<()> {
+    let generator = GENERATORS.get(day.wrapping_sub(1))
+        .ok_or_else(|| format!("Day {} is not implemented (expected 1-{})", day, GENERATORS.len()))?;
+    let size = size.unwrap_or(generator.default_size);
+    let seed = seed.unwrap_or_else(|| {
+        let seed = rand::random();
+        eprintln!("Generating {} {} with seed {}", size, generator.unit, seed);
+        seed
+    });
+    let mut out: Box<dyn Write> = match output {
+        Some(path) => Box::new(BufWriter::new(File::create(&path).map_err(|source| Error::Io { file: Some(path), source })?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+    (generator.generate)(&mut gen::rng(seed), size, &mut out)?;
+    out.flush()?;
+    Ok(())
+}
+
+fn parse_timings_arg(arg: &str) -> std::result::Result<(String, PathBuf), String> {
+    match arg.split_once('=') {
+        Some((who, path)) if !who.is_empty() && !path.is_empty() => Ok((who.to_string(), PathBuf::from(path))),
+        _ => Err(format!("Expected NAME=FILE, got {:?}", arg)),
+    }
+}
+
+fn leaderboard_report(input: &Path, timings: &[(String, PathBuf)], format: Format) -> Result<()> {
+    let board = Leaderboard::load(input)?;
+    let mut own = HashMap::new();
+    for (who, path) in timings {
+        let member = board.find(who).ok_or_else(|| format!("No member of the leaderboard is called {:?}", who))?;
+        own.insert(member.id.clone(), leaderboard::load_timings(path)?);
+    }
+    match format {
+        Format::Text => print!("{}", leaderboard::report_text(&board, &own)),
+        Format::Json => {
+            for obj in leaderboard::report_json(&board, &own) { println!("{}", obj); }
+        },
+    }
+    Ok(())
+}
+
+fn track_changes(input: Option<&Path>, connect: Option<&str>, keep_going: bool, wide: bool) -> Result<()> {
+    let reader: Box<dyn BufRead> = match connect {
+        Some(addr) => {
+            let stream = TcpStream::connect(addr).map_err(|e| Error::Other(format!("Couldn't connect to {}: {}", addr, e)))?;
+            Box::new(BufReader::new(stream))
+        },
+        None => match input {
+            Some(path) if path != Path::new("-") => {
+                Box::new(BufReader::new(File::open(path).map_err(|source| Error::Io { file: Some(path.to_path_buf()), source })?))
+            },
+            _ => Box::new(BufReader::new(io::stdin())),
+        },
+    };
+    let tracker = track::track(reader, &mut io::stdout(), Width::new(wide), keep_going)?;
+    if !tracker.has_repeated() {
+        return Err(From::from(format!("The stream ended after {} changes without a frequency repeating", tracker.changes_applied())));
+    }
+    Ok(())
+}
+
+fn reach_targets(input: &Path, targets: &[i128], wide: bool, raw: bool) -> Result<()> {
+    let puzzle_input = aoc_common::load_input(input, &RunOptions { raw, ..RunOptions::default() })?;
+    let changes = Day1::parse(puzzle_input.reader()).map_err(|e| e.in_file(&puzzle_input.path))?;
+    reach::report(&changes, targets, Width::new(wide), &mut io::stdout()).map_err(|e| e.in_file(&puzzle_input.path))
+}
+
+fn main() -> Result<()> {
+    match Cli::from_args() {
+        Cli::Run { day, inputs, all, opts } => {
+            if all {
+                if opts.answers.is_some() {
+                    return Err(From::from("--answers can't be used with --all"));
+                }
+                let mut failed = vec![];
+                for day in 1..=DAYS.len() {
+                    // JSON objects already carry their day, and stdout should stay parseable
+                    if opts.format == Format::Text { println!("Day {}", day); }
+                    if let Err(e) = run_day(day, vec![], &opts) {
+                        match opts.format {
+                            Format::Text => println!("Day {} failed: {}", day, e),
+                            Format::Json => eprintln!("Day {} failed: {}", day, e),
+                        }
+                        failed.push(day.to_string());
+                    }
+                }
+                if !failed.is_empty() {
+                    return Err(From::from(format!("Failed days: {}", failed.join(", "))));
+                }
+                Ok(())
+            } else {
+                run_day(day.unwrap_or(0), inputs, &opts)
+            }
+        }
+        Cli::Watch { day, input, release } => {
+            if day == 0 || day > DAYS.len() {
+                return Err(From::from(format!("Day {} is not implemented (expected 1-{})", day, DAYS.len())));
+            }
+            watch::watch(day, &input.unwrap_or_else(|| default_input(day)), release)
+        },
+        Cli::New { day } => new::new_day(&workspace_root(), day, DAYS.len()),
+        Cli::Fetch { day, server } => fetch_day(day, &server),
+        Cli::Submit { day, part, answer, server } => match server.client()?.submit(day, part, &answer)? {
+            Verdict::Right => {
+                println!("{}", Verdict::Right);
+                Ok(())
+            },
+            verdict => Err(Error::Other(verdict.to_string())),
+        },
+        Cli::Gen { day, size, seed, output } => gen_day(day, size, seed, output),
+        Cli::Leaderboard { input, timings, format } => leaderboard_report(&input, &timings, format),
+        Cli::Track { input, connect, keep_going, wide } => track_changes(input.as_deref(), connect.as_deref(), keep_going, wide),
+        Cli::Reach { input, targets, wide, raw } => reach_targets(&input.unwrap_or_else(|| default_input(1)), &targets, wide, raw),
+        Cli::History { day, part, threshold, flagged, file } => {
+            history::show(&file.unwrap_or_else(aoc_common::history::default_path), day, part, threshold, flagged)
+        },
+    }
+}
@@ -0,0 +1,75 @@
+//! `aoc new`: scaffolds the crate for the next day and registers it with the workspace and runner
+
+use std::fs;
+use std::path::Path;
+use aoc_common::{Error, Result};
+
+/// The files of a new day's crate, relative to its directory, and the templates they're made from
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    ("src/gen.rs", include_str!("../templates/gen.rs.tmpl")),
+    ("tests/examples.rs", include_str!("../templates/examples.rs.tmpl")),
+    ("tests/test1.txt", ""),
+    ("input/in.txt", ""),
+];
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    let io_error = |source| Error::Io { file: Some(path.to_path_buf()), source };
+    if let Some(dir) = path.parent() { fs::create_dir_all(dir).map_err(io_error)?; }
+    fs::write(path, contents).map_err(io_error)
+}
+
+/// Inserts `line` after the last line of `contents` that starts with `after`, keeping its indentation
+fn insert_after_last(contents: &str, after: &str, line: &str) -> Result<String> {
+    let lines = contents.lines().collect::<Vec<&str>>();
+    let idx = lines.iter().rposition(|l| l.trim_start().starts_with(after))
+        .ok_or_else(|| format!("Couldn't find where to add {:?}", line.trim()))?;
+    let indent = &lines[idx][..lines[idx].len() - lines[idx].trim_start().len()];
+    let mut out = lines[..=idx].to_vec();
+    let new_line = format!("{}{}", indent, line);
+    out.push(&new_line);
+    out.extend(&lines[idx + 1..]);
+    Ok(out.join("\n") + "\n")
+}
+
+fn edit_file<F: FnOnce(&str) -> Result<String>>(path: &Path, f: F) -> Result<()> {
+    let contents = fs::read_to_string(path).map_err(|source| Error::Io { file: Some(path.to_path_buf()), source })?;
+    let edited = f(&contents).map_err(|e| Error::Other(format!("{}: {}", path.display(), e)))?;
+    write_file(path, &edited)
+}
+
+/// Creates `aoc_<day>` in the workspace at `root` and adds it to the workspace members, the
+/// runner's dependencies and its table of days. Only the day after the last one can be added,
+/// since the runner looks days up by their position.
+pub fn new_day(root: &Path, day: usize, num_days: usize) -> Result<()> {
+    if day != num_days + 1 {
+        return Err(From::from(format!("Days 1-{} already exist, so the next new day must be {}", num_days, num_days + 1)));
+    }
+    let name = format!("aoc_{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(From::from(format!("{} already exists", dir.display())));
+    }
+
+    let authors = env!("CARGO_PKG_AUTHORS").split(':').map(|a| format!("{:?}", a)).collect::<Vec<_>>().join(", ");
+    for (file, template) in TEMPLATES {
+        let contents = template.replace("{{day}}", &day.to_string()).replace("{{authors}}", &authors);
+        write_file(&dir.join(file), &contents)?;
+    }
+
+    let prev = format!("aoc_{}", day - 1);
+    edit_file(&root.join("Cargo.toml"), |c| insert_after_last(c, &format!("\"{}\"", prev), &format!("\"{}\",", name)))?;
+    edit_file(&root.join("aoc").join("Cargo.toml"), |c| {
+        insert_after_last(c, &format!("{} =", prev), &format!("{} = {{ path = \"../{}\" }}", name, name))
+    })?;
+    edit_file(&root.join("aoc").join("src").join("days.rs"), |c| {
+        let c = insert_after_last(c, &format!("aoc_common::run::<{}::", prev), &format!("aoc_common::run::<{}::Day{}>,", name, day))?;
+        insert_after_last(&c, &format!("{}::gen::", prev), &format!("{}::gen::GENERATOR,", name))
+    })?;
+
+    println!("Created {} and registered it with the workspace and runner.", name);
+    println!("Put the puzzle input in {}/input/in.txt and the example in {}/tests/test1.txt.", name, name);
+    Ok(())
+}
@@ -0,0 +1,27 @@
+//! `aoc reach`: when day 1's frequency first reaches each target, or which frequencies it ever
+//! reaches, worked out without simulating the cycles
+
+use std::io::Write;
+use aoc_1::{first_reach, reachable, Width};
+use aoc_common::Result;
+
+/// Prints when each of `targets` is first reached, or the set of reachable frequencies if there
+/// are no targets
+pub fn report<W: Write>(frequency_changes: &[i64], targets: &[i128], width: Width, out: &mut W) -> Result<()> {
+    if targets.is_empty() {
+        writeln!(out, "Reachable frequencies: {}", reachable(frequency_changes))?;
+    }
+    for &target in targets {
+        match first_reach(frequency_changes, target, width)? {
+            Some(reach) => match reach.at {
+                Some(at) => match reach.changes_applied {
+                    Some(applied) => writeln!(out, "{}: first reached at {}, after {} changes", target, at, applied)?,
+                    None => writeln!(out, "{}: first reached at {}, after at least 2^128 changes", target, at)?,
+                },
+                None => writeln!(out, "{}: the starting frequency", target)?,
+            },
+            None => writeln!(out, "{}: never reached", target)?,
+        }
+    }
+    Ok(())
+}
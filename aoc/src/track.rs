@@ -0,0 +1,26 @@
+//! `aoc track`: feeds day 1's `FrequencyTracker` from a stream of changes, one per line, such as
+//! stdin or a TCP connection, reporting the first repeat as soon as it arrives
+
+use std::io::{BufRead, Write};
+use aoc_1::{parse_change, FrequencyTracker, Width};
+use aoc_common::{Error, Result};
+
+/// Applies each change read from `reader` until the first repeat, or until the stream ends if
+/// `keep_going` is set, printing the repeat to `out` as soon as it's reached. Blank lines are
+/// skipped, so a device can send them to keep a connection alive.
+pub fn track<R: BufRead, W: Write>(reader: R, out: &mut W, width: Width, keep_going: bool) -> Result<FrequencyTracker> {
+    let mut tracker = FrequencyTracker::new(width);
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        let text = line.trim();
+        if text.is_empty() { continue }
+        let change = parse_change(idx + 1, text)?;
+        if let Some(repeat) = tracker.push(change).map_err(|overflow| Error::invalid(idx + 1, text, overflow.to_string()))? {
+            writeln!(out, "First frequency reached twice: {} (after {} changes)", repeat.frequency, repeat.changes_applied)?;
+            out.flush()?;
+            if !keep_going { return Ok(tracker) }
+        }
+    }
+    writeln!(out, "Frequency after {} changes: {}", tracker.changes_applied(), tracker.frequency())?;
+    Ok(tracker)
+}
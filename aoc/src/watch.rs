@@ -0,0 +1,128 @@
+//! `aoc watch`: rebuilds and re-runs a day whenever its source or input changes, showing how the
+//! answers and timings compare to the previous run
+
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde_json::Value;
+use aoc_common::{config_path, Error, Result};
+
+/// How long to wait for more changes after one, so saving several files only triggers one run
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// One part's result, as read from the day's JSON output
+struct PartResult {
+    description: String,
+    answer: String,
+    parse_time: Duration,
+    part_time: Duration,
+}
+
+/// Each part's result from one run, by part number
+type RunResults = BTreeMap<u64, PartResult>;
+
+fn notify_error(e: notify::Error) -> Error {
+    Error::Other(format!("Couldn't watch for changes: {}", e))
+}
+
+/// Builds and runs the day's binary through cargo. Build errors go straight to stderr.
+fn run_once(root: &Path, day: usize, input: &Path, release: bool) -> Result<RunResults> {
+    let mut cmd = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    cmd.current_dir(root).args(["run", "-q", "-p", &format!("aoc_{}", day)]);
+    if release { cmd.arg("--release"); }
+    cmd.arg("--").arg("-f").arg(input).args(["--format", "json"]);
+    let output = cmd.stderr(Stdio::inherit()).output()?;
+    if !output.status.success() {
+        return Err(From::from(format!("Day {} failed to build or run", day)));
+    }
+
+    let mut results = RunResults::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let obj = serde_json::from_str::<Value>(line).map_err(|e| Error::Other(format!("Unexpected output {:?}: {}", line, e)))?;
+        let nanos = |key: &str| Duration::from_nanos(obj["timing"][key].as_u64().unwrap_or(0));
+        let answer = match &obj["answer"] {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        results.insert(obj["part"].as_u64().unwrap_or(0), PartResult {
+            description: obj["description"].as_str().unwrap_or("").to_string(),
+            answer,
+            parse_time: nanos("parse_ns"),
+            part_time: nanos("part_ns"),
+        });
+    }
+    Ok(results)
+}
+
+/// A time, with how much it changed from the previous one if there was one
+fn format_time(time: Duration, previous: Option<Duration>) -> String {
+    match previous {
+        Some(previous) if !previous.is_zero() => {
+            let change = (time.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+            format!("{:?} ({:+.0}% from {:?})", time, change, previous)
+        },
+        _ => format!("{:?}", time),
+    }
+}
+
+fn print_results(results: &RunResults, previous: Option<&RunResults>) {
+    if let Some(first) = results.values().next() {
+        let previous_parse = previous.and_then(|p| p.values().next()).map(|r| r.parse_time);
+        println!("parse: {}", format_time(first.parse_time, previous_parse));
+    }
+    for (part, result) in results {
+        let previous = previous.and_then(|p| p.get(part));
+        let status = match previous {
+            None => String::new(),
+            Some(p) if p.answer == result.answer => " [unchanged]".to_string(),
+            Some(p) => format!(" [changed, was {}]", p.answer),
+        };
+        println!("{}: {}{}", result.description, result.answer, status);
+        println!("part{}: {}", part, format_time(result.part_time, previous.map(|p| p.part_time)));
+    }
+}
+
+/// Whether an event could change the results, rather than just being the run reading the files
+fn is_relevant(event: &notify::Event, src: &Path, input: &Path) -> bool {
+    !matches!(event.kind, EventKind::Access(_))
+        && event.paths.iter().any(|path| path.starts_with(src) || path == input || *path == config_path(input))
+}
+
+/// Runs the day, then again after every change to its `src` directory, input or the input's
+/// `config.toml` until interrupted
+pub fn watch(day: usize, input: &Path, release: bool) -> Result<()> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").canonicalize()?;
+    let src = root.join(format!("aoc_{}", day)).join("src");
+    let input = input.canonicalize().map_err(|source| Error::Io { file: Some(input.to_path_buf()), source })?;
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event| { let _ = tx.send(event); }).map_err(notify_error)?;
+    watcher.watch(&src, RecursiveMode::Recursive).map_err(notify_error)?;
+    // Watch the input's directory rather than the file, so editors that save by replacing it are noticed
+    let input_dir = input.parent().map(PathBuf::from).unwrap_or_else(|| root.clone());
+    watcher.watch(&input_dir, RecursiveMode::NonRecursive).map_err(notify_error)?;
+
+    let mut previous: Option<RunResults> = None;
+    loop {
+        println!("== Running day {} on {}", day, input.display());
+        match run_once(&root, day, &input, release) {
+            Ok(results) => {
+                print_results(&results, previous.as_ref());
+                previous = Some(results);
+            },
+            // Keep comparing against the last good run
+            Err(e) => println!("{}", e),
+        }
+        println!("== Waiting for changes to {} or {}", src.display(), input.display());
+
+        loop {
+            let event = rx.recv().map_err(|_| Error::Other("Stopped watching for changes".to_string()))?;
+            if event.is_ok_and(|e| is_relevant(&e, &src, &input)) { break }
+        }
+        while rx.recv_timeout(SETTLE_TIME).is_ok() {}
+    }
+}
@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tiny_http::{Response, Server};
+use aoc::client::{parse_verdict, Client, Fetched, Verdict};
+
+/// A request the stub server received
+#[derive(Debug)]
+struct Request {
+    method: String,
+    url: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+/// A local stand-in for the puzzle site, answering every request with `page` and `status` and
+/// recording what it was asked
+fn stub_server(page: &'static str, status: u16) -> (String, Arc<Mutex<Vec<Request>>>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let requests = Arc::new(Mutex::new(vec![]));
+    let recorded = Arc::clone(&requests);
+    thread::spawn(move || {
+        for mut request in server.incoming_requests() {
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let cookie = request.headers().iter().find(|h| h.field.equiv("Cookie")).map(|h| h.value.to_string());
+            recorded.lock().unwrap().push(Request { method: request.method().to_string(), url: request.url().to_string(), cookie, body });
+            request.respond(Response::from_string(page).with_status_code(status)).unwrap();
+        }
+    });
+    (base_url, requests)
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc_client_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn fetch_downloads_once_then_uses_the_cache() {
+    let (base_url, requests) = stub_server("+1\n-2\n", 200);
+    let path = temp_dir("fetch").join("input").join("in.txt");
+    let client = Client::new(&base_url, "abc123\n");
+
+    assert_eq!(client.fetch_input(1, &path).unwrap(), Fetched::Downloaded);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "+1\n-2\n");
+    assert_eq!(client.fetch_input(1, &path).unwrap(), Fetched::Cached);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].url, "/2018/day/1/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+}
+
+#[test]
+fn fetch_replaces_an_empty_input() {
+    let (base_url, _) = stub_server("abc\n", 200);
+    let path = temp_dir("empty").join("in.txt");
+    fs::write(&path, "").unwrap();
+    assert_eq!(Client::new(&base_url, "abc123").fetch_input(5, &path).unwrap(), Fetched::Downloaded);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "abc\n");
+}
+
+#[test]
+fn fetch_reports_server_errors() {
+    let (base_url, _) = stub_server("Puzzle inputs differ by user.  Please log in to get your puzzle input.", 400);
+    let path = temp_dir("error").join("in.txt");
+    let err = Client::new(&base_url, "expired").fetch_input(1, &path).unwrap_err().to_string();
+    assert!(err.contains("400") && err.contains("Please log in"), "{}", err);
+    assert!(!path.exists());
+}
+
+#[test]
+fn submit_posts_the_answer_and_parses_the_verdict() {
+    let page = "<html><main><article><p>That's the right answer! You are one gold star closer.</p></article></main></html>";
+    let (base_url, requests) = stub_server(page, 200);
+    assert_eq!(Client::new(&base_url, "abc123").submit(7, 2, "1040").unwrap(), Verdict::Right);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].url, "/2018/day/7/answer");
+    assert_eq!(requests[0].body, "level=2&answer=1040");
+}
+
+#[test]
+fn verdicts() {
+    assert_eq!(
+        parse_verdict("<article><p>That's not the right answer; your answer is too low.  If you're stuck...</p></article>"),
+        Verdict::Wrong { hint: Some("your answer is too low".to_string()) },
+    );
+    assert_eq!(
+        parse_verdict("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href=\"/2018/day/1\">[Return to Day 1]</a></p></article>"),
+        Verdict::TooSoon { wait: Some("34s".to_string()) },
+    );
+    assert_eq!(
+        parse_verdict("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+        Verdict::WrongLevel,
+    );
+    assert_eq!(parse_verdict("<p>Something <em>else</em></p>"), Verdict::Unrecognised("Something else".to_string()));
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+use aoc::leaderboard::{self, format_delta, Leaderboard};
+
+fn fixture(name: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("leaderboard").join(name)
+}
+
+fn export() -> Leaderboard {
+    Leaderboard::load(&fixture("export.json")).unwrap()
+}
+
+#[test]
+fn reads_string_and_number_timestamps() {
+    let board = export();
+    let ids = board.members.iter().map(|m| m.id.as_str()).collect::<Vec<&str>>();
+    assert_eq!(ids, ["100", "200", "300"]);
+    assert_eq!(board.members[0].stars[&1], [Some(1543640500), Some(1543641100)]);
+    assert_eq!(board.members[1].stars[&2], [Some(1543728000), None]);
+    assert_eq!(board.members[2].name, "(anonymous user #300)");
+}
+
+#[test]
+fn counts_stars_and_deltas() {
+    let board = export();
+    let ian = board.find("ian").unwrap();
+    assert_eq!(ian.star_count(), 4);
+    assert_eq!(ian.delta(1), Some(Duration::from_secs(600)));
+    assert_eq!(ian.delta(2), Some(Duration::from_secs(100)));
+    let grace = board.find("200").unwrap();
+    assert_eq!(grace.delta(1), Some(Duration::from_secs(3600)));
+    assert_eq!(grace.delta(2), None);
+    assert_eq!(format_delta(Duration::from_secs(3723)), "1:02:03");
+}
+
+#[test]
+fn ranks_by_local_score() {
+    let board = export();
+    let standings = board.standings()
+        .iter()
+        .map(|s| (s.rank, s.member.id.as_str(), s.stars, s.local_score))
+        .collect::<Vec<_>>();
+    // Matches the local_score the site put in the export
+    assert_eq!(standings, [(1, "100", 4, 11), (2, "200", 3, 7), (3, "300", 1, 1)]);
+}
+
+#[test]
+fn tied_members_share_a_rank() {
+    let board = Leaderboard::parse(r#"{"members": {
+        "1": {"name": "a", "completion_day_level": {}},
+        "2": {"name": "b", "completion_day_level": {}}
+    }}"#).unwrap();
+    assert_eq!(board.standings().iter().map(|s| s.rank).collect::<Vec<_>>(), [1, 1]);
+}
+
+#[test]
+fn rejects_malformed_exports() {
+    assert!(Leaderboard::parse("[]").is_err());
+    assert!(Leaderboard::parse(r#"{"members": {"1": {"name": 5}}}"#).is_err());
+    assert!(Leaderboard::parse(r#"{"members": {"1": {"name": "a", "completion_day_level": {"1": {"1": {}}}}}}"#).is_err());
+}
+
+#[test]
+fn joins_latest_timings() {
+    let board = export();
+    let timings = leaderboard::load_timings(&fixture("bench.csv")).unwrap();
+    assert_eq!(timings[&1].part1, Some(Duration::from_nanos(2000)));
+    assert_eq!(timings[&1].part2, Some(Duration::from_nanos(300000)));
+
+    let own = vec![("100".to_string(), timings)].into_iter().collect::<HashMap<_, _>>();
+    let report = leaderboard::report_json(&board, &own);
+    assert_eq!(report[0]["days"][0]["part2_ns"], 300000);
+    assert!(report[0]["days"][1]["part1_ns"].is_null());
+    assert!(report[1]["days"][0].get("part1_ns").is_none());
+
+    let text = leaderboard::report_text(&board, &own);
+    assert!(text.contains("0:10:00"), "{}", text);
+    assert!(text.contains("300µs"), "{}", text);
+}
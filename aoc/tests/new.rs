@@ -0,0 +1,78 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use aoc::new::new_day;
+
+const DAYS_RS: &str = "\
+pub const DAYS: &[RunFn] = &[
+    aoc_common::run::<aoc_1::Day1>,
+];
+
+pub const GENERATORS: &[Generator] = &[
+    aoc_1::gen::GENERATOR,
+];
+";
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &to.join(entry.file_name()));
+        } else {
+            fs::copy(entry.path(), to.join(entry.file_name())).unwrap();
+        }
+    }
+}
+
+/// A workspace with day 1 and a runner that are just enough to register day 2 with, and the real
+/// aoc_common for day 2 to build against
+fn workspace() -> PathBuf {
+    let real = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("new_day_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let write = |path: &str, contents: &str| {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    };
+    write("Cargo.toml", "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc_common\",\n    \"aoc_1\",\n]\n");
+    write("aoc/Cargo.toml", "[package]\nname = \"aoc\"\nversion = \"0.1.0\"\n\n[dependencies]\naoc_1 = { path = \"../aoc_1\" }\n");
+    write("aoc/src/lib.rs", "");
+    write("aoc/src/days.rs", DAYS_RS);
+    write("aoc_1/Cargo.toml", "[package]\nname = \"aoc_1\"\nversion = \"0.1.0\"\n");
+    write("aoc_1/src/lib.rs", "");
+    copy_dir(&real.join("aoc_common").join("src"), &root.join("aoc_common").join("src"));
+    fs::copy(real.join("aoc_common").join("Cargo.toml"), root.join("aoc_common").join("Cargo.toml")).unwrap();
+    // So the dependencies resolve to the versions already downloaded
+    fs::copy(real.join("Cargo.lock"), root.join("Cargo.lock")).unwrap();
+    root
+}
+
+#[test]
+fn adds_a_day_that_builds() {
+    let root = workspace();
+    new_day(&root, 2, 1).unwrap();
+
+    let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+    assert!(read("Cargo.toml").contains("    \"aoc_1\",\n    \"aoc_2\",\n]"));
+    assert!(read("aoc/Cargo.toml").contains("aoc_1 = { path = \"../aoc_1\" }\naoc_2 = { path = \"../aoc_2\" }\n"));
+    let days = read("aoc/src/days.rs");
+    assert!(days.contains("    aoc_common::run::<aoc_1::Day1>,\n    aoc_common::run::<aoc_2::Day2>,\n];"));
+    assert!(days.contains("    aoc_1::gen::GENERATOR,\n    aoc_2::gen::GENERATOR,\n];"));
+
+    // The target directory is kept between runs, so the dependencies only build the first time
+    let status = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["build", "--offline", "--quiet", "--all-targets", "-p", "aoc_2"])
+        .current_dir(&root)
+        .env("CARGO_TARGET_DIR", Path::new(env!("CARGO_TARGET_TMPDIR")).join("new_day_target"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "aoc_2 didn't build");
+
+    // The day exists now, and a day past the next one can't be added
+    assert!(new_day(&root, 2, 1).is_err());
+    assert!(new_day(&root, 4, 2).is_err());
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,19 @@
+use aoc::reach::report;
+use aoc_1::Width;
+
+#[test]
+fn reports_each_target() {
+    let mut out = vec![];
+    report(&[3, 3, 4, -2, -4], &[0, 14, -1], Width::Bits64, &mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "0: the starting frequency\n14: first reached at line 3 of cycle 2, after 8 changes\n-1: never reached\n",
+    );
+}
+
+#[test]
+fn lists_reachable_frequencies_without_targets() {
+    let mut out = vec![];
+    report(&[1, -3], &[], Width::Bits64, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "Reachable frequencies: {0 - 2k, 1 - 2k : k >= 0}\n");
+}
@@ -0,0 +1,47 @@
+use std::io::{BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use aoc::track::track;
+use aoc_1::Width;
+
+#[test]
+fn stops_at_the_first_repeat() {
+    let mut out = vec![];
+    let tracker = track(&b"+3\r\n+3\n\n +4\n-2\n-4\n+3\n+3\n"[..], &mut out, Width::Bits64, false).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "First frequency reached twice: 10 (after 7 changes)\n");
+    assert_eq!(tracker.changes_applied(), 7);
+}
+
+#[test]
+fn keeps_going_to_the_end() {
+    let mut out = vec![];
+    let tracker = track(&b"+1\n-1\n+5\n"[..], &mut out, Width::Bits64, true).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "First frequency reached twice: 0 (after 2 changes)\nFrequency after 3 changes: 5\n");
+    assert!(tracker.has_repeated());
+}
+
+#[test]
+fn reports_bad_lines() {
+    let e = track(&b"+1\nx\n"[..], &mut vec![], Width::Bits64, false).expect_err("x isn't a change");
+    assert_eq!(e.location().map(|l| l.line), Some(2));
+    // Overflows are reported at the change's line too
+    let e = track(&b"+9223372036854775807\n\n+1\n"[..], &mut vec![], Width::Bits64, false).expect_err("overflows");
+    assert_eq!(e.location().map(|l| l.line), Some(3));
+    assert!(e.to_string().contains("at change 2"), "{}", e);
+}
+
+#[test]
+fn reads_from_tcp() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    // A device that never stops sending changes
+    thread::spawn(move || {
+        let (mut conn, _) = listener.accept().unwrap();
+        for change in ["+7", "+7", "-2", "-7", "-4"].iter().cycle() {
+            if writeln!(conn, "{}", change).is_err() { break }
+        }
+    });
+    let mut out = vec![];
+    let tracker = track(BufReader::new(TcpStream::connect(addr).unwrap()), &mut out, Width::Bits64, false).unwrap();
+    assert_eq!(tracker.first_repeat().map(|r| r.frequency), Some(14));
+}
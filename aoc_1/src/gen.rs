@@ -0,0 +1,22 @@
+use std::io::Write;
+use rand::Rng;
+use aoc_common::gen::{GenRng, Generator};
+use aoc_common::Result;
+
+pub const GENERATOR: Generator = Generator { default_size: 1000, unit: "frequency changes", generate };
+
+/// Random changes, with the last one picked so a full pass drifts by only a little. With more
+/// changes than the drift, two frequencies in a pass must differ by a multiple of it, so part 2
+/// always finds a repeat.
+fn generate(rng: &mut GenRng, size: usize, out: &mut dyn Write) -> Result<()> {
+    let size = size.max(2);
+    let mut sum = 0i64;
+    for _ in 0..size - 1 {
+        let change = rng.gen_range(-100_000..=100_000);
+        sum += change;
+        writeln!(out, "{:+}", change)?;
+    }
+    let drift = rng.gen_range(-(size as i64 - 1).min(10)..=(size as i64 - 1).min(10));
+    writeln!(out, "{:+}", drift - sum)?;
+    Ok(())
+}
@@ -0,0 +1,285 @@
+pub mod gen;
+mod reach;
+mod tracker;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::prelude::*;
+use tracing::{debug, trace};
+use aoc_common::{numbered_lines, Answer, Error, Params, Result, Solution};
+
+pub use reach::{first_reach, reachable, Reach, Reachable};
+pub use tracker::{FrequencyTracker, Overflow, StreamRepeat};
+
+/// How wide the integers frequencies are kept in are, and so how far they can go before overflowing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Width {
+    Bits64,
+    /// Enough for any list of 64-bit changes that fits in memory: even 2^61 of them can only
+    /// total 2^124
+    Bits128,
+}
+
+impl Width {
+    /// 128 bits if `wide`, otherwise 64
+    pub fn new(wide: bool) -> Width {
+        if wide { Width::Bits128 } else { Width::Bits64 }
+    }
+
+    pub fn from_params(params: &Params) -> Width {
+        Width::new(params.wide)
+    }
+
+    pub fn bits(self) -> u32 {
+        match self {
+            Width::Bits64 => 64,
+            Width::Bits128 => 128,
+        }
+    }
+
+    fn min(self) -> i128 {
+        match self {
+            Width::Bits64 => i64::MIN as i128,
+            Width::Bits128 => i128::MIN,
+        }
+    }
+
+    fn max(self) -> i128 {
+        match self {
+            Width::Bits64 => i64::MAX as i128,
+            Width::Bits128 => i128::MAX,
+        }
+    }
+
+    /// What to add to an overflow error to suggest a wider width, if there is one
+    pub(crate) fn overflow_hint(self) -> &'static str {
+        if self == Width::Bits64 { " (--wide uses 128 bits)" } else { "" }
+    }
+
+    fn contains(self, frequency: i128) -> bool {
+        (self.min()..=self.max()).contains(&frequency)
+    }
+
+    /// `a + b`, or `None` if it's out of range
+    pub(crate) fn add(self, a: i128, b: i128) -> Option<i128> {
+        a.checked_add(b).filter(|&sum| self.contains(sum))
+    }
+}
+
+/// A point in applying the changes over and over: just after change `index` of cycle `cycle`,
+/// both 0-based
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub cycle: u128,
+    pub index: usize,
+}
+
+/// A point in applying the changes over and over: just before change `index` of cycle `cycle`.
+/// Ordered by when it's reached, even when there are too many changes before it to count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Step {
+    pub(crate) cycle: u128,
+    pub(crate) index: usize,
+}
+
+impl Step {
+    const START: Step = Step { cycle: 0, index: 0 };
+
+    /// The change that reaches this step, which mustn't be the start
+    fn position(self, num_changes: usize) -> Position {
+        match self.index {
+            0 => Position { cycle: self.cycle - 1, index: num_changes - 1 },
+            index => Position { cycle: self.cycle, index: index - 1 },
+        }
+    }
+
+    /// How many changes are applied before this step, if it's few enough to count
+    fn changes_applied(self, num_changes: usize) -> Option<u128> {
+        self.cycle.checked_mul(num_changes as u128)?.checked_add(self.index as u128)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {} of cycle {}", self.index + 1, self.cycle + 1)
+    }
+}
+
+/// The error for a frequency going out of range at `position`
+fn overflow(frequency_changes: &[i64], position: Position, width: Width) -> Error {
+    Error::invalid(
+        position.index + 1,
+        &format!("{:+}", frequency_changes[position.index]),
+        format!("Frequency overflows {} bits in cycle {}{}", width.bits(), position.cycle + 1, width.overflow_hint()),
+    )
+}
+
+/// One line of input, a change like `+7` or `-3`
+pub fn parse_change(line: usize, text: &str) -> Result<i64> {
+    text.parse::<i64>().map_err(|e| Error::parse(line, text, format!("Invalid frequency change ({})", e)))
+}
+
+/// The frequency after applying every change once
+pub fn resulting_frequency(frequency_changes: &[i64], width: Width) -> Result<i128> {
+    frequency_changes.iter().enumerate().try_fold(0, |curr, (index, &change)| {
+        width.add(curr, change as i128).ok_or_else(|| overflow(frequency_changes, Position { cycle: 0, index }, width))
+    })
+}
+
+/// The frequency before each change of the first cycle, and the drift each cycle adds to them.
+/// Summing one cycle in 128 bits can't overflow, so any overflow for a narrower width is left to
+/// `first_overflow`.
+fn cycle_sums(frequency_changes: &[i64]) -> (Vec<i128>, i128) {
+    let mut sums = Vec::with_capacity(frequency_changes.len());
+    let mut curr = 0i128;
+    for &change in frequency_changes {
+        sums.push(curr);
+        curr += change as i128;
+    }
+    (sums, curr)
+}
+
+/// The first frequency reached twice, and where it was reached both times
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Repeat {
+    pub frequency: i128,
+    pub at: Position,
+    /// `None` if it's the starting frequency of 0
+    pub first_seen: Option<Position>,
+    /// How many changes had been applied when the frequency repeated, counting every cycle. The
+    /// repeat is within 2^64 cycles per change, so this can only saturate with over 2^32 changes.
+    pub changes_applied: u128,
+}
+
+impl Repeat {
+    /// The repeat at `at` of the frequency first reached at `first`
+    fn new(frequency: i128, at: Step, first: Step, num_changes: usize) -> Repeat {
+        Repeat {
+            frequency,
+            at: at.position(num_changes),
+            first_seen: if first == Step::START { None } else { Some(first.position(num_changes)) },
+            changes_applied: at.changes_applied(num_changes).unwrap_or(u128::MAX),
+        }
+    }
+}
+
+/// When the frequency first goes out of range, when each cycle after the first drifts it by
+/// `drift`, or `None` if it never does. `sums` can go out of range themselves, as they're summed
+/// in 128 bits.
+fn first_overflow(sums: &[i128], drift: i128, width: Width) -> Option<Step> {
+    if let Some(index) = sums.iter().position(|&sum| !width.contains(sum)) { return Some(Step { cycle: 0, index }) }
+    if drift == 0 { return None }
+    // Each frequency in the cycle heads towards the limit in the direction of the drift, and the
+    // one after the last change is the first's plus a cycle's drift
+    let limit = if drift > 0 { width.max() } else { width.min() };
+    sums.iter().enumerate()
+        .filter_map(|(index, &sum)| Some(Step { cycle: (limit.abs_diff(sum) / drift.unsigned_abs()).checked_add(1)?, index }))
+        .min()
+}
+
+/// The first frequency reached twice when applying the changes over and over, or `None` if no
+/// frequency ever is, found without simulating every cycle. Fails if a frequency goes out of range
+/// for `width` before the repeat; inputs with no repeat drift out of any range eventually, but
+/// still give `None`.
+///
+/// After `k` full cycles, the frequency before change `t` is `sums[t] + k * drift`, where `sums`
+/// are the prefix sums of the first cycle and `drift` is their total. Unless the first cycle
+/// repeats itself, a later frequency `sums[t] + k * drift` can only match an earlier `sums[u]` in
+/// the same residue class modulo `drift`, `(sums[u] - sums[t]) / drift` cycles on. So the first
+/// repeat is the nearest such pair, found by sorting each residue class.
+pub fn first_repeat(frequency_changes: &[i64], width: Width) -> Result<Option<Repeat>> {
+    let n = frequency_changes.len();
+    let mut sums = Vec::with_capacity(n);
+    // The index of the change each frequency was first reached before
+    let mut seen = HashMap::new();
+    let mut curr = 0;
+    for (index, &change) in frequency_changes.iter().enumerate() {
+        if let Some(&first) = seen.get(&curr) {
+            debug!(frequency = curr, "first repeat within the first cycle");
+            return Ok(Some(Repeat::new(curr, Step { cycle: 0, index }, Step { cycle: 0, index: first }, n)));
+        }
+        seen.insert(curr, index);
+        sums.push(curr);
+        curr = width.add(curr, change as i128).ok_or_else(|| overflow(frequency_changes, Position { cycle: 0, index }, width))?;
+        trace!(index, change, frequency = curr);
+    }
+    let drift = curr;
+    debug!(drift);
+    if sums.is_empty() { return Ok(None) }
+
+    let repeat = if drift == 0 {
+        // Every frequency comes around again after each cycle, and 0 was the first
+        Repeat::new(0, Step { cycle: 1, index: 0 }, Step::START, n)
+    } else {
+        let mut classes: HashMap<i128, Vec<(i128, usize)>> = HashMap::new();
+        for (idx, &sum) in sums.iter().enumerate() {
+            classes.entry(sum.rem_euclid(drift)).or_default().push((sum, idx));
+        }
+        // The earliest step a frequency repeats at, and the index it was first reached before
+        let mut best: Option<(Step, usize)> = None;
+        for class in classes.values_mut() {
+            // Later cycles move each frequency towards the next one in the direction of the drift
+            if drift > 0 { class.sort_unstable() } else { class.sort_unstable_by(|a, b| b.cmp(a)) }
+            for pair in class.windows(2) {
+                let ((from, idx), (to, first_idx)) = (pair[0], pair[1]);
+                let candidate = (Step { cycle: to.abs_diff(from) / drift.unsigned_abs(), index: idx }, first_idx);
+                if best.is_none_or(|best| candidate.0 < best.0) { best = Some(candidate); }
+            }
+        }
+        match best {
+            Some((at, first_idx)) => {
+                let repeat = Repeat::new(sums[first_idx], at, Step { cycle: 0, index: first_idx }, n);
+                if let Some(overflowed) = first_overflow(&sums, drift, width).filter(|&overflowed| overflowed < at) {
+                    return Err(overflow(frequency_changes, overflowed.position(n), width));
+                }
+                repeat
+            },
+            None => return Ok(None),
+        }
+    };
+
+    debug!(frequency = repeat.frequency, at = %repeat.at, "first repeat");
+    Ok(Some(repeat))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+
+    const DAY: u8 = 1;
+    const DESCRIPTIONS: [&'static str; 2] = ["Resulting frequency", "First frequency reached twice"];
+
+    fn parse<R: BufRead>(reader: R) -> Result<Vec<i64>> {
+        numbered_lines(reader)
+            .map(|l| {
+                let (line, text) = l?;
+                parse_change(line, &text)
+            })
+            .collect::<Result<Vec<i64>>>()
+    }
+
+    fn part1(frequency_changes: &Vec<i64>, params: &Params) -> Result<Answer> {
+        debug!(changes = frequency_changes.len());
+        Ok(resulting_frequency(frequency_changes, Width::from_params(params))?.into())
+    }
+
+    fn part2(frequency_changes: &Vec<i64>, params: &Params) -> Result<Answer> {
+        let width = Width::from_params(params);
+        match first_repeat(frequency_changes, width)? {
+            Some(repeat) => {
+                let mut answer = Answer::from(repeat.frequency)
+                    .with_detail("cycle", repeat.at.cycle + 1)
+                    .with_detail("line", repeat.at.index + 1);
+                if let Some(first) = repeat.first_seen {
+                    answer = answer.with_detail("first_cycle", first.cycle + 1).with_detail("first_line", first.index + 1);
+                }
+                Ok(answer.with_detail("changes_applied", repeat.changes_applied))
+            },
+            None => Err(From::from(format!(
+                "No frequency is ever reached twice: the first cycle never repeats itself, and drifting by {} a cycle never lands on an earlier frequency",
+                cycle_sums(frequency_changes).1,
+            ))),
+        }
+    }
+}
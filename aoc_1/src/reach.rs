@@ -0,0 +1,105 @@
+//! When a given frequency is first reached, and which ones ever are, from the same prefix sums and
+//! drift `first_repeat` uses
+
+use std::collections::HashMap;
+use std::fmt;
+use tracing::debug;
+use aoc_common::Result;
+use crate::{cycle_sums, first_overflow, overflow, Position, Step, Width};
+
+/// How many cycles' drift take `from` to `to`, if a whole number of them ever do
+fn cycles_to(from: i128, to: i128, drift: i128) -> Option<u128> {
+    if from == to { return Some(0) }
+    if drift == 0 || (to > from) != (drift > 0) { return None }
+    let distance = to.abs_diff(from);
+    if distance.is_multiple_of(drift.unsigned_abs()) { Some(distance / drift.unsigned_abs()) } else { None }
+}
+
+/// When a frequency was first reached
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reach {
+    /// `None` for the starting frequency of 0
+    pub at: Option<Position>,
+    /// `None` if there are too many to count in 128 bits, though `at` is still exact
+    pub changes_applied: Option<u128>,
+}
+
+/// When `target` is first reached applying the changes over and over, or `None` if it never is,
+/// without simulating any cycles. Fails if a frequency goes out of range for `width` first, or the
+/// target itself is out of range.
+///
+/// The frequency before change `t` of cycle `k` is `sums[t] + k * drift`, so `target` is reached
+/// there when it's a whole number `k` of drifts on from `sums[t]`. The earliest `(k, t)` of those
+/// is the answer.
+pub fn first_reach(frequency_changes: &[i64], target: i128, width: Width) -> Result<Option<Reach>> {
+    if target == 0 { return Ok(Some(Reach { at: None, changes_applied: Some(0) })) }
+    let (sums, drift) = cycle_sums(frequency_changes);
+    let n = sums.len();
+    let step = sums.iter().enumerate()
+        .filter_map(|(index, &sum)| Some(Step { cycle: cycles_to(sum, target, drift)?, index }))
+        .min();
+    let step = match step {
+        Some(step) => step,
+        None => return Ok(None),
+    };
+    if let Some(overflowed) = first_overflow(&sums, drift, width).filter(|&overflowed| overflowed <= step) {
+        return Err(overflow(frequency_changes, overflowed.position(n), width));
+    }
+    let at = step.position(n);
+    debug!(target, %at, "first reached");
+    Ok(Some(Reach { at: Some(at), changes_applied: step.changes_applied(n) }))
+}
+
+/// Every frequency reached applying the changes over and over, ignoring overflow: the starts, and
+/// every whole number of drifts on from each of them
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reachable {
+    /// Sorted, and no two in the same residue class modulo the drift, so none is reached from another
+    pub starts: Vec<i128>,
+    pub drift: i128,
+}
+
+impl Reachable {
+    pub fn contains(&self, frequency: i128) -> bool {
+        self.starts.iter().any(|&start| cycles_to(start, frequency, self.drift).is_some())
+    }
+
+    /// Whether only the starts are reached, as the frequencies come back around after each cycle
+    pub fn is_finite(&self) -> bool {
+        self.drift == 0
+    }
+}
+
+impl fmt::Display for Reachable {
+    /// e.g. `{-2, 0, 3}`, or `{0 + 3k, 4 + 3k, 8 + 3k : k >= 0}` with a drift of 3
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let starts = self.starts.iter().map(|&start| match self.drift {
+            0 => start.to_string(),
+            drift if drift > 0 => format!("{} + {}k", start, drift),
+            drift => format!("{} - {}k", start, drift.unsigned_abs()),
+        }).collect::<Vec<_>>();
+        if self.is_finite() { write!(f, "{{{}}}", starts.join(", ")) } else { write!(f, "{{{} : k >= 0}}", starts.join(", ")) }
+    }
+}
+
+/// Which frequencies are ever reached. Each residue class modulo the drift only needs the start
+/// furthest back against the drift, as the rest of the class is reached from it.
+pub fn reachable(frequency_changes: &[i64]) -> Reachable {
+    let (sums, drift) = cycle_sums(frequency_changes);
+    let mut starts = if drift == 0 {
+        sums
+    } else {
+        let mut classes: HashMap<i128, i128> = HashMap::new();
+        for sum in sums {
+            let start = classes.entry(sum.rem_euclid(drift)).or_insert(sum);
+            *start = if drift > 0 { sum.min(*start) } else { sum.max(*start) };
+        }
+        classes.into_values().collect()
+    };
+    // With no changes, there's still the starting frequency
+    if starts.is_empty() { starts.push(0) }
+    starts.sort_unstable();
+    starts.dedup();
+    debug!(starts = starts.len(), drift, "reachable");
+    Reachable { starts, drift }
+}
@@ -0,0 +1,76 @@
+//! Following the frequency one change at a time, for devices that stream their changes rather
+//! than giving a whole list up front
+
+use std::collections::HashSet;
+use std::fmt;
+use crate::Width;
+
+/// A frequency reached for the second time, in a stream of changes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StreamRepeat {
+    pub frequency: i128,
+    /// How many changes had been applied when it was reached again
+    pub changes_applied: u128,
+}
+
+/// A change that would take the frequency out of range, which the caller can report at wherever
+/// the change came from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    pub width: Width,
+    /// Counting the change that overflowed
+    pub changes_applied: u128,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Frequency overflows {} bits at change {}{}", self.width.bits(), self.changes_applied, self.width.overflow_hint())
+    }
+}
+
+/// The frequency so far and the first one reached twice, updated with each change pushed
+#[derive(Clone, Debug)]
+pub struct FrequencyTracker {
+    width: Width,
+    frequency: i128,
+    changes_applied: u128,
+    /// Every frequency reached so far, until one repeats and it's no longer needed
+    seen: HashSet<i128>,
+    first_repeat: Option<StreamRepeat>,
+}
+
+impl FrequencyTracker {
+    pub fn new(width: Width) -> FrequencyTracker {
+        FrequencyTracker { width, frequency: 0, changes_applied: 0, seen: HashSet::from([0]), first_repeat: None }
+    }
+
+    /// Applies the next change, returning the first repeat if this change is what reached it.
+    /// Fails, leaving the tracker as it was, if the frequency would overflow.
+    pub fn push(&mut self, change: i64) -> Result<Option<StreamRepeat>, Overflow> {
+        let frequency = self.width.add(self.frequency, change as i128)
+            .ok_or(Overflow { width: self.width, changes_applied: self.changes_applied + 1 })?;
+        self.frequency = frequency;
+        self.changes_applied += 1;
+        if self.first_repeat.is_some() || self.seen.insert(frequency) { return Ok(None) }
+        let repeat = StreamRepeat { frequency, changes_applied: self.changes_applied };
+        self.first_repeat = Some(repeat);
+        self.seen = HashSet::new();
+        Ok(Some(repeat))
+    }
+
+    pub fn frequency(&self) -> i128 {
+        self.frequency
+    }
+
+    pub fn changes_applied(&self) -> u128 {
+        self.changes_applied
+    }
+
+    pub fn has_repeated(&self) -> bool {
+        self.first_repeat.is_some()
+    }
+
+    pub fn first_repeat(&self) -> Option<StreamRepeat> {
+        self.first_repeat
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::{Params, Solution};
+use aoc_common::testing::{assert_answer, example};
+use aoc_1::Day1;
+
+#[test]
+fn test1() {
+    let changes = example::<Day1>("tests/test1.txt");
+    assert_answer(Day1::part1(&changes, &Params::default()), 3);
+    assert_answer(Day1::part2(&changes, &Params::default()), 2);
+}
+
+#[test]
+fn test2() {
+    let changes = example::<Day1>("tests/test2.txt");
+    assert_answer(Day1::part1(&changes, &Params::default()), 4);
+    assert_answer(Day1::part2(&changes, &Params::default()), 10);
+}
+
+#[test]
+fn test3() {
+    let changes = example::<Day1>("tests/test3.txt");
+    assert_answer(Day1::part1(&changes, &Params::default()), 4);
+    assert_answer(Day1::part2(&changes, &Params::default()), 5);
+}
+
+#[test]
+fn test4() {
+    let changes = example::<Day1>("tests/test4.txt");
+    assert_answer(Day1::part1(&changes, &Params::default()), 1);
+    assert_answer(Day1::part2(&changes, &Params::default()), 14);
+}
@@ -0,0 +1,8 @@
+use aoc_common::testing::check_generator;
+use aoc_1::Day1;
+use aoc_1::gen::GENERATOR;
+
+#[test]
+fn generated_inputs_parse_and_solve() {
+    check_generator::<Day1>(&GENERATOR, 200);
+}
@@ -0,0 +1,49 @@
+use aoc_common::testing::example;
+use aoc_1::{first_reach, reachable, Day1, Position, Reach, Width};
+
+#[test]
+fn target_reach() {
+    // 0, 3, 6, 10, 8, then each of those 4 higher every cycle
+    let changes = example::<Day1>("tests/test2.txt");
+    assert_eq!(first_reach(&changes, 0, Width::Bits64).unwrap(), Some(Reach { at: None, changes_applied: Some(0) }));
+    assert_eq!(first_reach(&changes, 14, Width::Bits64).unwrap(), Some(Reach { at: Some(Position { cycle: 1, index: 2 }), changes_applied: Some(8) }));
+    assert_eq!(first_reach(&changes, 4_000_002, Width::Bits64).unwrap().and_then(|r| r.changes_applied), Some(4_999_993));
+    assert_eq!(first_reach(&changes, 5, Width::Bits64).unwrap(), None);
+    assert_eq!(first_reach(&changes, -4, Width::Bits64).unwrap(), None);
+
+    let reachable = reachable(&changes);
+    assert_eq!(reachable.starts, [0, 3, 6]);
+    assert_eq!(reachable.to_string(), "{0 + 4k, 3 + 4k, 6 + 4k : k >= 0}");
+    assert!(reachable.contains(4_000_002) && !reachable.contains(5) && !reachable.contains(-4));
+
+    // With no drift, only the first cycle's frequencies are ever reached
+    let reachable = aoc_1::reachable(&[3, -5, 2]);
+    assert!(reachable.is_finite());
+    assert_eq!(reachable.to_string(), "{-2, 0, 3}");
+}
+
+#[test]
+fn overflow_only_matters_before_the_target() {
+    // Past i64::MAX, the frequencies overflow first unless they're kept wide
+    assert!(first_reach(&[1 << 62], 1 << 64, Width::Bits64).is_err());
+    assert_eq!(first_reach(&[1 << 62], 1 << 64, Width::Bits128).unwrap().and_then(|r| r.changes_applied), Some(4));
+
+    // The first cycle overflows on line 2, but 1 is reached on line 1 before that
+    let changes = [1, i64::MAX];
+    assert_eq!(first_reach(&changes, 1, Width::Bits64).unwrap(), Some(Reach { at: Some(Position { cycle: 0, index: 0 }), changes_applied: Some(1) }));
+    let e = first_reach(&changes, 1 + i64::MAX as i128, Width::Bits64).expect_err("out of range");
+    assert_eq!(e.location().map(|l| l.line), Some(2));
+    let e = first_reach(&changes, 2 + i64::MAX as i128, Width::Bits64).expect_err("overflows on the way");
+    assert!(e.to_string().contains("cycle 1"), "{}", e);
+}
+
+#[test]
+fn too_many_changes_to_count() {
+    // 2^126 is reached after more than 2^128 changes, long before 128 bits overflow
+    let changes = [1, 0, 0, 0, 0];
+    let reach = first_reach(&changes, 1 << 126, Width::Bits128).unwrap().unwrap();
+    assert_eq!(reach.at, Some(Position { cycle: (1 << 126) - 1, index: 0 }));
+    assert_eq!(reach.changes_applied, None);
+    // As is the largest frequency, just before 128 bits would overflow
+    assert!(first_reach(&changes, i128::MAX, Width::Bits128).unwrap().is_some());
+}
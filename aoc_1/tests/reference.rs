@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use proptest::prelude::*;
+use aoc_1::{first_reach, first_repeat, reachable, Position, Width};
+
+/// Applies the changes over and over, giving up after `max_steps`. Returns the repeated frequency
+/// with how many changes had been applied when it was reached the first and second time.
+fn reference_first_repeat(changes: &[i64], max_steps: usize) -> Option<(i128, u128, u128)> {
+    let mut seen = HashMap::new();
+    let mut curr = 0i128;
+    for (applied, &change) in changes.iter().cycle().take(max_steps).enumerate() {
+        if let Some(&first) = seen.get(&curr) { return Some((curr, first, applied as u128)) }
+        seen.insert(curr, applied as u128);
+        curr += change as i128;
+    }
+    None
+}
+
+/// How many changes had been applied when `target` was first reached, giving up after `max_steps`
+fn reference_first_reach(changes: &[i64], target: i128, max_steps: usize) -> Option<u128> {
+    if target == 0 { return Some(0) }
+    let mut curr = 0i128;
+    for (applied, &change) in changes.iter().cycle().take(max_steps).enumerate() {
+        curr += change as i128;
+        if curr == target { return Some(applied as u128 + 1) }
+    }
+    None
+}
+
+/// The number of changes applied by the time `position` is reached
+fn applied(position: Option<Position>, num_changes: usize) -> u128 {
+    position.map_or(0, |p| p.cycle * num_changes as u128 + p.index as u128 + 1)
+}
+
+proptest! {
+    // With changes this small, any repeat happens well within the step limit, so not finding one
+    // means there isn't one
+    #[test]
+    fn first_repeat_matches_reference(changes in prop::collection::vec(-6i64..=6, 0..10)) {
+        let repeat = first_repeat(&changes, Width::Bits64).unwrap();
+        let found = repeat.map(|r| (r.frequency, applied(r.first_seen, changes.len()), applied(Some(r.at), changes.len())));
+        prop_assert_eq!(found, reference_first_repeat(&changes, 10_000));
+        if let Some(repeat) = repeat {
+            prop_assert_eq!(repeat.changes_applied, applied(Some(repeat.at), changes.len()));
+        }
+    }
+
+    // Any target in range is either reached within the step limit or drifted away from for good
+    #[test]
+    fn first_reach_matches_reference(changes in prop::collection::vec(-6i64..=6, 0..10), target in -60i128..=60) {
+        let reach = first_reach(&changes, target, Width::Bits64).unwrap();
+        prop_assert_eq!(reach.and_then(|r| r.changes_applied), reference_first_reach(&changes, target, 10_000));
+        if let Some(reach) = reach {
+            prop_assert_eq!(reach.changes_applied, Some(applied(reach.at, changes.len())));
+        }
+        prop_assert_eq!(reachable(&changes).contains(target), reach.is_some());
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_common::{Params, Solution};
+use aoc_common::testing::{assert_answer, example};
+use aoc_1::{first_repeat, Day1, Position, Width};
+
+#[test]
+fn never_repeats() {
+    assert!(Day1::part2(&vec![1, 1], &Params::default()).is_err());
+    assert!(Day1::part2(&vec![], &Params::default()).is_err());
+    assert_eq!(first_repeat(&[5, -2, 4], Width::Bits64).unwrap(), None);
+}
+
+#[test]
+fn drift_heavy() {
+    // Takes a million cycles to come back around to 1000000
+    let repeat = first_repeat(&[1_000_000, -999_999], Width::Bits64).unwrap().unwrap();
+    assert_eq!(repeat.frequency, 1_000_000);
+    assert_eq!(repeat.changes_applied, 2_000_000);
+    assert_eq!(first_repeat(&[-7, 3, -1_000_000, 1_000_003], Width::Bits64).unwrap().map(|r| r.frequency), Some(-7));
+}
+
+#[test]
+fn repeat_location() {
+    // 0, 3, 6, 10, 8, 4, then 7 and 10 again after the second cycle's second change
+    let changes = example::<Day1>("tests/test2.txt");
+    let repeat = first_repeat(&changes, Width::Bits64).unwrap().unwrap();
+    assert_eq!(repeat.frequency, 10);
+    assert_eq!(repeat.at, Position { cycle: 1, index: 1 });
+    assert_eq!(repeat.first_seen, Some(Position { cycle: 0, index: 2 }));
+    assert_eq!(repeat.changes_applied, 7);
+    assert_eq!(repeat.at.to_string(), "line 2 of cycle 2");
+
+    let answer = Day1::part2(&changes, &Params::default()).unwrap();
+    let details = answer.details().iter().map(|(name, value)| format!("{}={}", name, value)).collect::<Vec<_>>();
+    assert_eq!(details, ["cycle=2", "line=2", "first_cycle=1", "first_line=3", "changes_applied=7"]);
+
+    // The starting frequency has no position it was first seen at
+    let repeat = first_repeat(&[1, -1], Width::Bits64).unwrap().unwrap();
+    assert_eq!((repeat.frequency, repeat.first_seen, repeat.at), (0, None, Position { cycle: 0, index: 1 }));
+}
+
+#[test]
+fn overflow_is_reported_where_it_happens() {
+    let e = Day1::part1(&vec![i64::MAX, 1, -5], &Params::default()).expect_err("overflows");
+    assert_eq!(e.location().map(|l| l.line), Some(2));
+
+    // Drifting by 1 a cycle, the frequencies take about 2^62 cycles to repeat, but the one 10
+    // below i64::MAX after line 2 overflows in the 11th
+    let changes = vec![1 << 62, (1 << 62) - 10, i64::MIN + 11];
+    let e = first_repeat(&changes, Width::Bits64).expect_err("overflows");
+    assert_eq!(e.location().map(|l| l.line), Some(2));
+    assert!(e.to_string().contains("cycle 11"), "{}", e);
+    assert!(first_repeat(&changes, Width::Bits128).unwrap().is_some());
+}
+
+#[test]
+fn wide_mode() {
+    let changes = vec![i64::MAX, i64::MAX, -i64::MAX, -i64::MAX];
+    let wide = Params { wide: true, ..Params::default() };
+    assert!(Day1::part1(&changes, &Params::default()).is_err());
+    assert_answer(Day1::part1(&changes, &wide), 0);
+    // 2 * i64::MAX is the first repeat, which only fits in 128 bits
+    let changes = vec![i64::MAX, i64::MAX, -1, 1];
+    assert!(Day1::part2(&changes, &Params::default()).is_err());
+    assert_answer(Day1::part2(&changes, &wide), 2 * i64::MAX as i128);
+}
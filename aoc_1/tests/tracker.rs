@@ -0,0 +1,37 @@
+use aoc_1::{first_repeat, FrequencyTracker, Overflow, StreamRepeat, Width};
+
+#[test]
+fn agrees_with_first_repeat() {
+    for changes in [vec![1, -1], vec![3, 3, 4, -2, -4], vec![-6, 3, 8, 5, -6], vec![7, 7, -2, -7, -4]] {
+        let expected = first_repeat(&changes, Width::Bits64).unwrap().unwrap();
+        let mut tracker = FrequencyTracker::new(Width::Bits64);
+        let repeat = changes.iter().cycle().find_map(|&change| tracker.push(change).unwrap()).unwrap();
+        assert_eq!(repeat, StreamRepeat { frequency: expected.frequency, changes_applied: expected.changes_applied });
+        assert_eq!(tracker.frequency(), expected.frequency);
+    }
+}
+
+#[test]
+fn keeps_following_after_the_repeat() {
+    let mut tracker = FrequencyTracker::new(Width::Bits64);
+    assert_eq!(tracker.push(1).unwrap(), None);
+    assert!(!tracker.has_repeated());
+    assert_eq!(tracker.push(-1).unwrap(), Some(StreamRepeat { frequency: 0, changes_applied: 2 }));
+    // Only the first repeat is reported
+    assert_eq!(tracker.push(1).unwrap(), None);
+    assert_eq!((tracker.frequency(), tracker.changes_applied()), (1, 3));
+    assert_eq!(tracker.first_repeat(), Some(StreamRepeat { frequency: 0, changes_applied: 2 }));
+}
+
+#[test]
+fn overflow_leaves_the_tracker_unchanged() {
+    let mut tracker = FrequencyTracker::new(Width::Bits64);
+    tracker.push(i64::MAX).unwrap();
+    assert_eq!(tracker.push(1), Err(Overflow { width: Width::Bits64, changes_applied: 2 }));
+    assert_eq!((tracker.frequency(), tracker.changes_applied()), (i64::MAX as i128, 1));
+
+    let mut wide = FrequencyTracker::new(Width::Bits128);
+    wide.push(i64::MAX).unwrap();
+    wide.push(1).unwrap();
+    assert_eq!(wide.frequency(), i64::MAX as i128 + 1);
+}
@@ -0,0 +1,29 @@
+use std::io::Write;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use aoc_common::gen::{GenRng, Generator};
+use aoc_common::Result;
+
+pub const GENERATOR: Generator = Generator { default_size: 250, unit: "box IDs", generate };
+
+const ID_LENGTH: usize = 26;
+
+fn random_id(rng: &mut GenRng) -> Vec<u8> {
+    (0..ID_LENGTH).map(|_| rng.gen_range(b'a'..=b'z')).collect()
+}
+
+/// Random IDs, plus one pair differing in a single position for part 2 to find
+fn generate(rng: &mut GenRng, size: usize, out: &mut dyn Write) -> Result<()> {
+    let size = size.max(2);
+    let mut ids = (0..size - 1).map(|_| random_id(rng)).collect::<Vec<Vec<u8>>>();
+    let mut twin = ids[rng.gen_range(0..ids.len())].clone();
+    let idx = rng.gen_range(0..ID_LENGTH);
+    twin[idx] = b'a' + (twin[idx] - b'a' + rng.gen_range(1..26)) % 26;
+    ids.push(twin);
+    ids.shuffle(rng);
+    for id in ids {
+        out.write_all(&id)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,76 @@
+pub mod gen;
+
+use std::io::prelude::*;
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, trace};
+use aoc_common::{numbered_lines, Answer, Error, Params, Result, Solution};
+
+fn get_repeat_info(box_id: &str, alphabet: &[char]) -> Result<(bool, bool)> {
+    if !box_id.is_ascii() { return Err(From::from("Box ID not ASCII")); }
+    let mut counts = alphabet.iter().map(|&c| (c, 0)).collect::<HashMap<char, usize>>();
+    for c in box_id.to_lowercase().chars() {
+        if let Some(count) = counts.get_mut(&c) { *count += 1; }
+    }
+    Ok((counts.iter().any(|(_, &c)| c == 2), (counts.iter().any(|(_, &c)| c == 3))))
+}
+
+fn get_diff_indices(box_id_a: &str, box_id_b: &str) -> Result<HashSet<usize>> {
+    if box_id_a.len() != box_id_b.len() { return Err(From::from("Box IDs have different lengths")); }
+    let ret = box_id_a.chars().zip(box_id_b.chars()).enumerate()
+        .filter_map(|(idx, (c_a, c_b))| if c_a != c_b { Some(idx) } else { None })
+        .collect::<HashSet<usize>>();
+    Ok(ret)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<String>;
+
+    const DAY: u8 = 2;
+    const DESCRIPTIONS: [&'static str; 2] = ["Checksum", "Common characters"];
+
+    fn parse<R: BufRead>(reader: R) -> Result<Vec<String>> {
+        let mut box_ids: Vec<String> = vec![];
+        for l in numbered_lines(reader) {
+            let (line, text) = l?;
+            if let Some((idx, _)) = text.char_indices().find(|(_, c)| !c.is_ascii()) {
+                return Err(Error::invalid(line, &text, "Box ID not ASCII").at_column(idx + 1));
+            }
+            if box_ids.first().is_some_and(|first| first.len() != text.len()) {
+                return Err(Error::invalid(line, &text, "Box ID length differs from the first box ID's"));
+            }
+            box_ids.push(text);
+        }
+        Ok(box_ids)
+    }
+
+    fn part1(box_ids: &Vec<String>, params: &Params) -> Result<Answer> {
+        let alphabet = params.alphabet()?;
+        let (mut with_two, mut with_three) = (0usize, 0usize);
+        for box_id in box_ids {
+            let (has_two, has_three) = get_repeat_info(box_id, &alphabet)?;
+            trace!(%box_id, has_two, has_three);
+            if has_two { with_two += 1; }
+            if has_three { with_three += 1; }
+        }
+        debug!(with_two, with_three);
+        Ok((with_two * with_three).into())
+    }
+
+    fn part2(box_ids: &Vec<String>, _params: &Params) -> Result<Answer> {
+        for box_id_a in box_ids {
+            for box_id_b in box_ids {
+                let diff_indices = get_diff_indices(box_id_a, box_id_b)?;
+                if diff_indices.len() == 1 {
+                    let common = box_id_a.char_indices()
+                        .filter_map(|(idx, c)| if diff_indices.contains(&idx) { None } else { Some(c) })
+                        .collect::<String>();
+                    debug!(%box_id_a, %box_id_b, "box IDs 1 character apart");
+                    return Ok(common.into());
+                }
+            }
+        }
+        Err(From::from("No box IDs with 1 character difference found"))
+    }
+}
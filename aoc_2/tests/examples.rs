@@ -0,0 +1,15 @@
+use aoc_common::{Params, Solution};
+use aoc_common::testing::{assert_answer, example};
+use aoc_2::Day2;
+
+#[test]
+fn test1() {
+    let box_ids = example::<Day2>("tests/test1.txt");
+    assert_answer(Day2::part1(&box_ids, &Params::default()), 12);
+}
+
+#[test]
+fn test2() {
+    let box_ids = example::<Day2>("tests/test2.txt");
+    assert_answer(Day2::part2(&box_ids, &Params::default()), "fgij");
+}
@@ -0,0 +1,8 @@
+use aoc_common::testing::check_generator;
+use aoc_2::Day2;
+use aoc_2::gen::GENERATOR;
+
+#[test]
+fn generated_inputs_parse_and_solve() {
+    check_generator::<Day2>(&GENERATOR, 50);
+}
@@ -0,0 +1,34 @@
+use proptest::prelude::*;
+use aoc_common::{Params, Solution};
+use aoc_2::Day2;
+
+/// Compares every pair of IDs in order, returning the common letters of the first pair that differs in exactly one position
+fn reference_part2(box_ids: &[String]) -> Option<String> {
+    for (i, a) in box_ids.iter().enumerate() {
+        for b in &box_ids[i + 1..] {
+            let same = a.chars().zip(b.chars()).filter(|(x, y)| x == y).map(|(x, _)| x).collect::<String>();
+            if same.len() + 1 == a.len() { return Some(same); }
+        }
+    }
+    None
+}
+
+/// Up to a dozen IDs of the same length
+fn box_ids() -> impl Strategy<Value = Vec<String>> {
+    (1usize..6).prop_flat_map(|len| {
+        let id = proptest::string::string_regex(&format!("[a-c]{{{}}}", len)).unwrap();
+        prop::collection::vec(id, 0..12)
+    })
+}
+
+proptest! {
+    // A small alphabet and short IDs so that many inputs have a pair one letter apart
+    #[test]
+    fn part2_matches_reference(box_ids in box_ids()) {
+        let expected = reference_part2(&box_ids);
+        match Day2::part2(&box_ids, &Params::default()) {
+            Ok(answer) => prop_assert_eq!(Some(answer.to_string()), expected),
+            Err(_) => prop_assert_eq!(None, expected),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::io::Write;
+use rand::Rng;
+use aoc_common::gen::{GenRng, Generator};
+use aoc_common::Result;
+use crate::FABRIC_SIZE;
+
+pub const GENERATOR: Generator = Generator { default_size: 1300, unit: "claims", generate };
+
+type Rect = ((usize, usize), (usize, usize));
+
+fn overlaps(a: &Rect, b: &Rect) -> bool {
+    let ((ax, ay), (aw, ah)) = *a;
+    let ((bx, by), (bw, bh)) = *b;
+    ax < bx + bw && bx < ax + aw && ay < by + bh && by < ay + ah
+}
+
+/// A random claim, pushed up against the fabric's far edges half the time
+fn random_claim(rng: &mut GenRng) -> Rect {
+    let dimensions = (rng.gen_range(1..=30), rng.gen_range(1..=30));
+    let mut top_left = (rng.gen_range(0..=FABRIC_SIZE - dimensions.0), rng.gen_range(0..=FABRIC_SIZE - dimensions.1));
+    if rng.gen_bool(0.25) { top_left.0 = FABRIC_SIZE - dimensions.0; }
+    if rng.gen_bool(0.25) { top_left.1 = FABRIC_SIZE - dimensions.1; }
+    (top_left, dimensions)
+}
+
+/// Random claims, one of which is kept clear of all the others for part 2 to find
+fn generate(rng: &mut GenRng, size: usize, out: &mut dyn Write) -> Result<()> {
+    let size = size.max(1);
+    let intact_number = rng.gen_range(1..=size);
+    let intact = random_claim(rng);
+    for number in 1..=size {
+        let claim = if number == intact_number {
+            intact
+        } else {
+            loop {
+                let claim = random_claim(rng);
+                if !overlaps(&claim, &intact) { break claim }
+            }
+        };
+        let ((x, y), (w, h)) = claim;
+        writeln!(out, "#{} @ {},{}: {}x{}", number, x, y, w, h)?;
+    }
+    Ok(())
+}
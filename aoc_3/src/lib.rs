@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate lazy_static;
+
+pub mod gen;
+
+use std::io::prelude::*;
+use regex::Regex;
+use tracing::{debug, trace};
+use aoc_common::{numbered_lines, Answer, Error, Grid, Params, Point, Result, Solution};
+
+#[derive(Debug)]
+pub struct Claim {
+    /// The line of input the claim was on, and its text, to report it if it doesn't fit the fabric
+    line: usize,
+    text: String,
+    number: usize,
+    top_left: (usize, usize),
+    dimensions: (usize, usize),
+}
+
+impl Claim {
+    fn parse(line: usize, text: &str) -> Result<Claim> {
+        lazy_static! {
+            static ref CLAIM_REGEX: Regex = Regex::new(r"^#(\d+) @ (\d+),(\d+): (\d+)x(\d+)$").unwrap();
+        }
+        let captures = CLAIM_REGEX.captures(text)
+            .ok_or_else(|| Error::parse(line, text, "Invalid fabric claim, expected \"#<id> @ <x>,<y>: <w>x<h>\""))?;
+        let field = |idx: usize| {
+            let m = captures.get(idx).unwrap();
+            m.as_str().parse::<usize>()
+                .map_err(|e| Error::parse(line, text, format!("Invalid number ({})", e)).at_column(m.start() + 1))
+        };
+        Ok(Claim{
+            line,
+            text: text.to_string(),
+            number: field(1)?,
+            top_left: (field(2)?, field(3)?),
+            dimensions: (field(4)?, field(5)?)
+        })
+    }
+
+    /// Every square inch inside the claim
+    fn points(&self) -> impl Iterator<Item = Point> {
+        let (left, top) = (self.top_left.0 as i32, self.top_left.1 as i32);
+        let (width, height) = (self.dimensions.0 as i32, self.dimensions.1 as i32);
+        (top..top + height).flat_map(move |y| (left..left + width).map(move |x| (x, y)))
+    }
+}
+
+/// The real puzzle's fabric width and height
+pub const FABRIC_SIZE: usize = 1000;
+
+/// How many claims cover each square inch of a `size` by `size` piece of fabric
+pub fn claim_grid(claims: &[Claim], size: usize) -> Result<Grid<usize>> {
+    // Every square inch has to have a point, and the counts have to fit in memory
+    let cells = size.checked_mul(size).and_then(|cells| cells.checked_mul(std::mem::size_of::<usize>()));
+    if size > i32::MAX as usize || cells.is_none_or(|bytes| bytes > isize::MAX as usize) {
+        return Err(From::from(format!("A fabric size of {} is too big", size)));
+    }
+    let mut grid = Grid::new((0, 0), size, size, 0)?;
+    for claim in claims {
+        let fits = |start: usize, length: usize| start.checked_add(length).is_some_and(|end| end <= size);
+        if !fits(claim.top_left.0, claim.dimensions.0) || !fits(claim.top_left.1, claim.dimensions.1) {
+            return Err(Error::invalid(claim.line, &claim.text, format!("Claim extends past the edge of the {}x{} fabric", size, size)));
+        }
+        for point in claim.points() {
+            grid[point] += 1;
+        }
+        trace!(claim = claim.number, top_left = ?claim.top_left, dimensions = ?claim.dimensions);
+    }
+    Ok(grid)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Claim>;
+
+    const DAY: u8 = 3;
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "Square inches inside more than one claim",
+        "Claim overlapping with no other claims",
+    ];
+
+    fn parse<R: BufRead>(reader: R) -> Result<Vec<Claim>> {
+        numbered_lines(reader).map(|l| { let (line, text) = l?; Claim::parse(line, &text) }).collect::<Result<Vec<Claim>>>()
+    }
+
+    fn part1(claims: &Vec<Claim>, params: &Params) -> Result<Answer> {
+        let grid = claim_grid(claims, params.fabric_size.unwrap_or(FABRIC_SIZE))?;
+        let overlapping = grid.values().filter(|&&count| count > 1).count();
+        debug!(claims = claims.len(), overlapping, "square inches in more than one claim");
+        Ok(overlapping.into())
+    }
+
+    fn part2(claims: &Vec<Claim>, params: &Params) -> Result<Answer> {
+        let grid = claim_grid(claims, params.fabric_size.unwrap_or(FABRIC_SIZE))?;
+        let non_overlapping = claims.iter().find(|claim| claim.points().all(|point| grid[point] == 1));
+        match non_overlapping {
+            Some(claim) => {
+                debug!(claim = claim.number, top_left = ?claim.top_left, dimensions = ?claim.dimensions, "intact claim");
+                Ok(claim.number.into())
+            },
+            None => Err(From::from("All claims overlapped with at least one other claim")),
+        }
+    }
+}
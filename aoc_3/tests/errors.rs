@@ -0,0 +1,21 @@
+use aoc_common::{Params, Solution};
+use aoc_3::Day3;
+
+#[test]
+fn claims_past_the_edge() {
+    let claims = Day3::parse(&b"#1 @ 1,1: 2x2\n#2 @ 18446744073709551615,1: 1x1\n"[..]).unwrap();
+    let e = Day3::part1(&claims, &Params::default()).expect_err("claim #2 overflows");
+    assert_eq!(e.location().map(|l| l.line), Some(2));
+    let params = Params { fabric_size: Some(2), ..Params::default() };
+    let claims = Day3::parse(&b"#1 @ 1,1: 2x2\n"[..]).unwrap();
+    assert!(Day3::part2(&claims, &params).is_err());
+}
+
+#[test]
+fn fabric_too_big() {
+    let claims = Day3::parse(&b"#1 @ 1,1: 2x2\n"[..]).unwrap();
+    for size in [3_000_000_000, usize::MAX] {
+        let params = Params { fabric_size: Some(size), ..Params::default() };
+        assert!(Day3::part1(&claims, &params).is_err());
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_common::{Params, Solution};
+use aoc_common::testing::{assert_answer, example};
+use aoc_3::Day3;
+
+#[test]
+fn test1() {
+    let claims = example::<Day3>("tests/test1.txt");
+    assert_answer(Day3::part1(&claims, &Params::default()), 4);
+    assert_answer(Day3::part2(&claims, &Params::default()), 3);
+}
@@ -0,0 +1,8 @@
+use aoc_common::testing::check_generator;
+use aoc_3::Day3;
+use aoc_3::gen::GENERATOR;
+
+#[test]
+fn generated_inputs_parse_and_solve() {
+    check_generator::<Day3>(&GENERATOR, 50);
+}
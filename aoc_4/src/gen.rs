@@ -0,0 +1,39 @@
+use std::io::Write;
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use rand::Rng;
+use rand::seq::SliceRandom;
+use aoc_common::gen::{GenRng, Generator};
+use aoc_common::Result;
+
+pub const GENERATOR: Generator = Generator { default_size: 200, unit: "shifts", generate };
+
+/// A log of one shift a day, starting shortly before or after midnight, with up to three
+/// naps in the midnight hour. The first shift always has a nap, so part 1 has a sleepiest guard.
+/// As in the real logs, the lines are shuffled.
+fn generate(rng: &mut GenRng, size: usize, out: &mut dyn Write) -> Result<()> {
+    let guards = (0..(size / 20).max(1)).map(|_| rng.gen_range(1..4000)).collect::<Vec<usize>>();
+    let first_day = NaiveDate::from_ymd_opt(1518, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let mut lines = vec![];
+    for day in 0..size as i64 {
+        let midnight = first_day + Duration::days(day);
+        let start = midnight + Duration::minutes(rng.gen_range(-30..=5));
+        lines.push((start, format!("Guard #{} begins shift", guards.choose(rng).unwrap())));
+
+        let num_naps = if day == 0 { rng.gen_range(1..=3) } else { rng.gen_range(0..=3) };
+        let mut minutes = (6..60).collect::<Vec<i64>>().choose_multiple(rng, num_naps * 2).cloned().collect::<Vec<i64>>();
+        minutes.sort_unstable();
+        for nap in minutes.chunks(2) {
+            lines.push((midnight + Duration::minutes(nap[0]), "falls asleep".to_string()));
+            lines.push((midnight + Duration::minutes(nap[1]), "wakes up".to_string()));
+        }
+    }
+    lines.shuffle(rng);
+    for (time, action) in lines {
+        writeln!(out, "[{}] {}", format_time(time), action)?;
+    }
+    Ok(())
+}
+
+fn format_time(time: NaiveDateTime) -> String {
+    time.format("%Y-%m-%d %H:%M").to_string()
+}
@@ -0,0 +1,124 @@
+pub mod gen;
+
+use std::io::prelude::*;
+use std::collections::HashMap;
+use chrono::prelude::*;
+use tracing::{debug, trace};
+use aoc_common::{numbered_lines, Answer, Error, Params, Result, Solution};
+
+/// Each guard's naps, as `[start, end)` minutes past midnight
+pub type SleepIntervals = HashMap<usize, Vec<(u32, u32)>>;
+
+#[derive(Debug)]
+enum GuardActionType {
+    FallAsleep,
+    WakeUp,
+    BeginShift(usize)
+}
+
+#[derive(Debug)]
+struct GuardAction {
+    time: DateTime<Utc>,
+    action_type: GuardActionType
+}
+
+impl GuardAction {
+    fn parse(line: usize, input: &str) -> Result<GuardAction> {
+        let split_time_action = input.splitn(2, "] ").collect::<Vec<&str>>();
+        if split_time_action.len() != 2 {
+            return Err(Error::parse(line, input, "Invalid format, expected \"[<timestamp>] <action>\""));
+        }
+        let naive_time = NaiveDateTime::parse_from_str(split_time_action[0].trim_start_matches('['), "%Y-%m-%d %H:%M")
+            .map_err(|e| Error::parse(line, input, format!("Invalid timestamp ({})", e)).at_column(2))?;
+        let time = Utc.from_utc_datetime(&naive_time);
+        let action_column = split_time_action[0].len() + 3;
+        let action_type = match split_time_action[1] {
+            "falls asleep" => GuardActionType::FallAsleep,
+            "wakes up" => GuardActionType::WakeUp,
+            raw => {
+                let guard_number = raw.trim_start_matches("Guard #").trim_end_matches(" begins shift").parse::<usize>()
+                    .map_err(|_| Error::parse(line, input, "Invalid action").at_column(action_column))?;
+                GuardActionType::BeginShift(guard_number)
+            },
+        };
+        Ok( GuardAction{ time, action_type } )
+    }
+}
+
+fn get_guard_sleep_intervals(actions: &[GuardAction]) -> SleepIntervals {
+    let mut guard_sleep: SleepIntervals = HashMap::new();
+    let mut curr_guard: Option<usize> = None;
+    let mut sleep_start: Option<u32> = None;
+    for action in actions {
+        match action.action_type {
+            GuardActionType::BeginShift(guard_id) => curr_guard = Some(guard_id),
+            GuardActionType::FallAsleep => sleep_start = Some(action.time.minute()),
+            GuardActionType::WakeUp => {
+                let entry = guard_sleep.entry(curr_guard.unwrap_or(0)).or_default();
+                entry.push((sleep_start.unwrap_or(0), action.time.minute()));
+                sleep_start = None;
+            }
+        }
+    }
+    guard_sleep
+}
+
+fn get_most_commonly_slept_minute(guard_id: usize, naps: &[(u32, u32)]) -> (usize, u32) {
+    let mut minute_sleep_counts = vec![0; 60];
+    for nap in naps {
+        for minute in nap.0..nap.1 {
+            minute_sleep_counts[minute as usize] += 1;
+        }
+    }
+    trace!(guard_id, histogram = ?minute_sleep_counts, "naps per minute");
+
+    minute_sleep_counts.iter().enumerate().fold((0, 0u32), |curr_best, (min, &freq)| {
+        if freq > curr_best.1 { (min, freq) } else { curr_best }
+    })
+}
+
+const NO_SLEEP: &str = "No guard ever falls asleep";
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = SleepIntervals;
+
+    const DAY: u8 = 4;
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "ID of the guard who slept the most times their most slept minute",
+        "ID of the guard most frequently asleep on the same minute times that minute",
+    ];
+
+    fn parse<R: BufRead>(reader: R) -> Result<SleepIntervals> {
+        let mut guard_actions = numbered_lines(reader).map(|l| { let (line, text) = l?; GuardAction::parse(line, &text) }).collect::<Result<Vec<GuardAction>>>()?;
+        guard_actions.sort_by_key(|ga| ga.time);
+        Ok(get_guard_sleep_intervals(&guard_actions))
+    }
+
+    fn part1(sleep_intervals: &SleepIntervals, _params: &Params) -> Result<Answer> {
+        let (most_sleep_guard_id, most_sleep) = sleep_intervals.iter().fold((0, 0), |curr_best, (&id, naps)| {
+            let sleep_total = naps.iter().fold(0, |acc, (start, end)| acc + (end - start));
+            debug!(guard_id = id, sleep_total);
+            if sleep_total > curr_best.1 { (id, sleep_total) } else { curr_best }
+        });
+        if most_sleep == 0 { return Err(From::from(NO_SLEEP)); }
+
+        let (most_often_slept_minute, _) = get_most_commonly_slept_minute(most_sleep_guard_id, &sleep_intervals[&most_sleep_guard_id]);
+        Ok(Answer::from(most_sleep_guard_id * most_often_slept_minute)
+            .with_detail("guard_id", most_sleep_guard_id)
+            .with_detail("minute", most_often_slept_minute))
+    }
+
+    fn part2(sleep_intervals: &SleepIntervals, _params: &Params) -> Result<Answer> {
+        let (guard_id, minute, count) = sleep_intervals.iter().fold((0, 0, 0), |curr_best, (&id, naps)| {
+            let (minute, count) = get_most_commonly_slept_minute(id, naps);
+            debug!(guard_id = id, minute, count, "most slept minute");
+            if count > curr_best.2 { (id, minute, count) } else { curr_best }
+        });
+        if count == 0 { return Err(From::from(NO_SLEEP)); }
+        Ok(Answer::from(guard_id * minute)
+            .with_detail("guard_id", guard_id)
+            .with_detail("minute", minute))
+    }
+}
@@ -0,0 +1,11 @@
+use aoc_common::{Params, Solution};
+use aoc_4::Day4;
+
+#[test]
+fn no_guard_sleeps() {
+    for input in [&b""[..], &b"[1518-11-01 00:00] Guard #10 begins shift\n"[..]] {
+        let sleep_intervals = Day4::parse(input).unwrap();
+        assert!(Day4::part1(&sleep_intervals, &Params::default()).is_err());
+        assert!(Day4::part2(&sleep_intervals, &Params::default()).is_err());
+    }
+}
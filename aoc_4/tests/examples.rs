@@ -0,0 +1,17 @@
+use aoc_common::{Answer, Params, Solution};
+use aoc_common::testing::{assert_answer, example};
+use aoc_4::Day4;
+
+#[test]
+fn test1() {
+    let sleep_intervals = example::<Day4>("tests/test1.txt");
+    assert_answer(Day4::part1(&sleep_intervals, &Params::default()), 240);
+    assert_answer(Day4::part2(&sleep_intervals, &Params::default()), 4455);
+}
+
+#[test]
+fn test1_details() {
+    let sleep_intervals = example::<Day4>("tests/test1.txt");
+    let answer = Day4::part1(&sleep_intervals, &Params::default()).unwrap();
+    assert_eq!(answer.details(), &[("guard_id", Answer::from(10usize)), ("minute", Answer::from(24usize))]);
+}
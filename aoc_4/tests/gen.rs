@@ -0,0 +1,8 @@
+use aoc_common::testing::check_generator;
+use aoc_4::Day4;
+use aoc_4::gen::GENERATOR;
+
+#[test]
+fn generated_inputs_parse_and_solve() {
+    check_generator::<Day4>(&GENERATOR, 50);
+}
@@ -0,0 +1,35 @@
+use std::io::Write;
+use rand::Rng;
+use aoc_common::gen::{GenRng, Generator};
+use aoc_common::Result;
+
+pub const GENERATOR: Generator = Generator { default_size: 50_000, unit: "units", generate };
+
+fn flip_case(unit: u8) -> u8 {
+    unit ^ 0x20
+}
+
+/// A polymer with plenty of reactions, including nested ones like `abBA`: each unit either
+/// reacts with the most recent unit that hasn't reacted yet, or is a new random unit.
+/// Like the real input, it has no trailing newline.
+fn generate(rng: &mut GenRng, size: usize, out: &mut dyn Write) -> Result<()> {
+    let mut unreacted = vec![];
+    let mut polymer = Vec::with_capacity(size);
+    for _ in 0..size {
+        let unit = match unreacted.last() {
+            Some(&last) if rng.gen_bool(0.4) => {
+                unreacted.pop();
+                flip_case(last)
+            },
+            _ => {
+                let unit = rng.gen_range(b'a'..=b'z');
+                let unit = if rng.gen_bool(0.5) { flip_case(unit) } else { unit };
+                unreacted.push(unit);
+                unit
+            },
+        };
+        polymer.push(unit);
+    }
+    out.write_all(&polymer)?;
+    Ok(())
+}
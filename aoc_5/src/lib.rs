@@ -0,0 +1,73 @@
+pub mod gen;
+
+use std::io::prelude::*;
+use tracing::debug;
+use aoc_common::{Answer, Error, Params, Result, Solution};
+
+fn react_once(polymer: &str) -> String {
+    let mut out: Vec<char> = vec![];
+    polymer.chars().for_each(|c| {
+        if let Some(prev) = out.last() {
+            if prev.eq_ignore_ascii_case(&c) && prev.is_ascii_lowercase() != c.is_ascii_lowercase() {
+                out.pop();
+            } else {
+                out.push(c);
+            }
+        } else {
+            out.push(c)
+        }
+    });
+    out.iter().collect()
+}
+
+/// The polymer left once no more units react, after removing every unit of type `without`
+pub fn fully_react(polymer: &str, without: Option<char>) -> String {
+    let mut last = polymer.chars()
+        .filter(|&c| without.is_none() || c.to_ascii_lowercase() != without.unwrap())
+        .collect::<String>();
+    let mut curr = react_once(&last);
+    while !curr.is_empty() && curr != last {
+        last = curr;
+        curr = react_once(&last);
+    }
+    curr
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = String;
+
+    const DAY: u8 = 5;
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "Units remaining after polymer was fully reacted",
+        "Shortest possible polymer with 1 unit type removal",
+    ];
+
+    fn parse<R: BufRead>(mut reader: R) -> Result<String> {
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+        if let Some((idx, c)) = contents.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            let line_start = contents[..idx].rfind('\n').map_or(0, |i| i + 1);
+            let line_end = contents[idx..].find('\n').map_or(contents.len(), |i| idx + i);
+            let line = contents[..idx].matches('\n').count() + 1;
+            let column = contents[line_start..idx].chars().count() + 1;
+            return Err(Error::parse(line, &contents[line_start..line_end], format!("Invalid polymer unit {:?}", c)).at_column(column));
+        }
+        Ok(contents)
+    }
+
+    fn part1(polymer: &String, _params: &Params) -> Result<Answer> {
+        debug!(units = polymer.len());
+        Ok(fully_react(polymer, None).len().into())
+    }
+
+    fn part2(polymer: &String, params: &Params) -> Result<Answer> {
+        let min_reacted_length = params.alphabet()?.into_iter().fold(usize::MAX, |acc, c| {
+            let fully_reacted = fully_react(polymer, Some(c));
+            debug!(without = %c, length = fully_reacted.len());
+            if fully_reacted.len() < acc { fully_reacted.len() } else { acc }
+        });
+        Ok(min_reacted_length.into())
+    }
+}
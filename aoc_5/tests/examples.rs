@@ -0,0 +1,17 @@
+use aoc_common::{Params, Solution};
+use aoc_common::testing::{assert_answer, example};
+use aoc_5::Day5;
+
+#[test]
+fn test1() {
+    let polymer = example::<Day5>("tests/test1.txt");
+    assert_answer(Day5::part1(&polymer, &Params::default()), 10);
+    assert_answer(Day5::part2(&polymer, &Params::default()), 4);
+}
+
+#[test]
+fn rejects_anything_but_units() {
+    // What a raw input with a trailing newline looks like; the runner normalizes it away
+    let e = Day5::parse(&b"dabAcC\n"[..]).expect_err("a newline isn't a unit");
+    assert_eq!(e.location().map(|l| (l.line, l.column)), Some((1, Some(7))));
+}
@@ -0,0 +1,8 @@
+use aoc_common::testing::check_generator;
+use aoc_5::Day5;
+use aoc_5::gen::GENERATOR;
+
+#[test]
+fn generated_inputs_parse_and_solve() {
+    check_generator::<Day5>(&GENERATOR, 1000);
+}
@@ -0,0 +1,24 @@
+use proptest::prelude::*;
+use aoc_5::fully_react;
+
+/// Removes the first reacting pair of units, if there is one
+fn react_first_pair(polymer: &mut Vec<char>) -> bool {
+    let pair = polymer.windows(2).position(|w| w[0] != w[1] && w[0].eq_ignore_ascii_case(&w[1]));
+    if let Some(idx) = pair { polymer.drain(idx..idx + 2); }
+    pair.is_some()
+}
+
+/// Reacts one pair at a time until none are left
+fn reference_fully_react(polymer: &str, without: Option<char>) -> String {
+    let mut units = polymer.chars().filter(|c| Some(c.to_ascii_lowercase()) != without).collect::<Vec<char>>();
+    while react_first_pair(&mut units) {}
+    units.into_iter().collect()
+}
+
+proptest! {
+    #[test]
+    fn fully_react_matches_reference(polymer in "[a-dA-D]{0,80}", without in proptest::option::of("[a-d]")) {
+        let without = without.and_then(|w| w.chars().next());
+        prop_assert_eq!(fully_react(&polymer, without), reference_fully_react(&polymer, without));
+    }
+}
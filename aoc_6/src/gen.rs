@@ -0,0 +1,21 @@
+use std::collections::HashSet;
+use std::io::Write;
+use rand::Rng;
+use aoc_common::gen::{GenRng, Generator};
+use aoc_common::Result;
+
+pub const GENERATOR: Generator = Generator { default_size: 50, unit: "points", generate };
+
+/// Distinct points scattered around the origin, so roughly three quarters of them have a
+/// negative coordinate. The spread grows with the number of points.
+fn generate(rng: &mut GenRng, size: usize, out: &mut dyn Write) -> Result<()> {
+    let spread = (size as i32 * 4).max(10);
+    let mut points = HashSet::new();
+    while points.len() < size.max(1) {
+        let point = (rng.gen_range(-spread..=spread), rng.gen_range(-spread..=spread));
+        if points.insert(point) {
+            writeln!(out, "{}, {}", point.0, point.1)?;
+        }
+    }
+    Ok(())
+}
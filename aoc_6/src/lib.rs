@@ -0,0 +1,155 @@
+pub mod gen;
+
+use std::io::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use tracing::debug;
+use aoc_common::{numbered_lines, Answer, Error, Grid, Params, Result, Solution};
+
+#[derive(Clone)]
+struct Tile {
+    status: VoronoiStatus,
+}
+
+#[derive(Clone)]
+enum VoronoiStatus {
+    Unknown,
+    Conflict(usize),
+    ClosestTo(usize, usize),
+}
+
+pub struct VoronoiGrid {
+    grid: Grid<Tile>,
+    points: Vec<(i32, i32)>,
+}
+
+impl VoronoiGrid {
+    pub fn parse<R: BufRead>(reader: R) -> Result<VoronoiGrid> {
+        let points = numbered_lines(reader).map(|l| {
+            let (line, text) = l?;
+            let split = text.splitn(2, ", ").collect::<Vec<&str>>();
+            if split.len() != 2 { return Err(Error::parse(line, &text, "Invalid point, expected \"<x>, <y>\"")) }
+            let coordinate = |s: &str, column: usize| {
+                let c = s.parse::<i32>().map_err(|e| Error::parse(line, &text, format!("Invalid coordinate ({})", e)).at_column(column))?;
+                // The grid goes one past the points in every direction
+                if c == i32::MIN || c == i32::MAX {
+                    return Err(Error::invalid(line, &text, "Coordinate leaves no room around the point for the grid").at_column(column));
+                }
+                Ok(c)
+            };
+            Ok((coordinate(split[0], 1)?, coordinate(split[1], split[0].len() + 3)?))
+        }).collect::<Result<Vec<(i32, i32)>>>()?;
+        if points.is_empty() { return Err(Error::Other("No points in input".to_string())) }
+
+        // Calculate bounds of grid
+        let mut top_left = (i32::MAX, i32::MAX);
+        let mut bottom_right = (i32::MIN, i32::MIN);
+        for point in &points {
+            let p = *point;
+            if p.0 < top_left.0 { top_left.0 = p.0 }
+            if p.1 < top_left.1 { top_left.1 = p.1 }
+            if p.0 > bottom_right.0 { bottom_right.0 = p.0 }
+            if p.1 > bottom_right.1 { bottom_right.1 = p.1 }
+        }
+        // Expand bounds by 1 in all directions so we can detect infinite areas
+        top_left = (top_left.0 - 1, top_left.1 - 1);
+        bottom_right = (bottom_right.0 + 1, bottom_right.1 + 1);
+        debug!(points = points.len(), ?top_left, ?bottom_right, "grid bounds");
+
+        // Calculate status of each point in the grid
+        let (width, height) = (1 + bottom_right.0 as i64 - top_left.0 as i64, 1 + bottom_right.1 as i64 - top_left.1 as i64);
+        let grid = Grid::from_fn(top_left, width as usize, height as usize, |(x, y)| {
+            let mut curr_status = VoronoiStatus::Unknown;
+            for (idx, point) in points.iter().enumerate() {
+                let dist = ((point.0 as i64 - x as i64).abs() + (point.1 as i64 - y as i64).abs()) as usize;
+                curr_status = match curr_status {
+                    VoronoiStatus::Unknown => VoronoiStatus::ClosestTo(idx, dist),
+                    VoronoiStatus::Conflict(d) => {
+                        if dist < d { VoronoiStatus::ClosestTo(idx, dist) } else { curr_status }
+                    },
+                    VoronoiStatus::ClosestTo(_, d) => {
+                        match dist.cmp(&d) {
+                            std::cmp::Ordering::Less => VoronoiStatus::ClosestTo(idx, dist),
+                            std::cmp::Ordering::Equal => VoronoiStatus::Conflict(d),
+                            std::cmp::Ordering::Greater => curr_status
+                        }
+                    },
+                }
+            }
+            Tile{ status: curr_status }
+        })?;
+        Ok(VoronoiGrid { grid, points })
+    }
+}
+
+/// The total distance along one axis from each coordinate in `range` to every point's `coords`
+fn axis_totals(coords: &[i64], range: std::ops::RangeInclusive<i64>) -> Vec<usize> {
+    range.map(|c| coords.iter().map(|&p| (p - c).unsigned_abs() as usize).sum()).collect()
+}
+
+/// Number of locations whose total distance to every point is below `threshold`. This isn't
+/// limited to the grid: a location `d` past the points' bounds on either axis is at least `d` from
+/// every point, so the region can reach `threshold / points` beyond them. The total distance is
+/// the x distances' total plus the y distances', so each axis is summed on its own and the pairs
+/// under the threshold are counted.
+pub fn region_size(grid: &VoronoiGrid, threshold: usize) -> usize {
+    let margin = (threshold / grid.points.len()) as i64;
+    let axis = |coord: fn(&(i32, i32)) -> i32| {
+        let coords = grid.points.iter().map(|p| coord(p) as i64).collect::<Vec<i64>>();
+        let (min, max) = (*coords.iter().min().unwrap(), *coords.iter().max().unwrap());
+        axis_totals(&coords, min - margin..=max + margin)
+    };
+    let x_totals = axis(|p| p.0);
+    let mut y_totals = axis(|p| p.1);
+    y_totals.sort_unstable();
+    debug!(margin, "region bounds");
+    x_totals.iter()
+        .filter(|&&x_total| x_total < threshold)
+        .map(|&x_total| y_totals.partition_point(|&y_total| y_total < threshold - x_total))
+        .sum()
+}
+
+/// The real puzzle's total distance threshold
+pub const DISTANCE_THRESHOLD: usize = 10000;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = VoronoiGrid;
+
+    const DAY: u8 = 6;
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "Largest non-infinite area",
+        "Size of region containing all locations with total distance under the threshold",
+    ];
+
+    fn parse<R: BufRead>(reader: R) -> Result<VoronoiGrid> {
+        VoronoiGrid::parse(reader)
+    }
+
+    fn part1(grid: &VoronoiGrid, _params: &Params) -> Result<Answer> {
+        // Areas reaching the edge of the grid carry on forever
+        let infinite_areas = grid.grid.border().filter_map(|(_, tile)| match tile.status {
+            VoronoiStatus::ClosestTo(idx, _) => Some(idx),
+            _ => None,
+        }).collect::<BTreeSet<usize>>();
+        debug!(?infinite_areas, "points with infinite areas");
+
+        let mut areas: HashMap<usize, usize> = HashMap::new();
+        for tile in grid.grid.values() {
+            if let VoronoiStatus::ClosestTo(idx, _) = tile.status {
+                if !infinite_areas.contains(&idx) {
+                    *areas.entry(idx).or_insert(0) += 1;
+                }
+            }
+        }
+
+        debug!(finite_areas = areas.len());
+        let largest_area = areas.values().max().unwrap_or(&0);
+        Ok((*largest_area).into())
+    }
+
+    fn part2(grid: &VoronoiGrid, params: &Params) -> Result<Answer> {
+        let threshold = params.distance_threshold.unwrap_or(DISTANCE_THRESHOLD);
+        Ok(Answer::from(region_size(grid, threshold)).with_detail("distance_threshold", threshold))
+    }
+}
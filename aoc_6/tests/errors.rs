@@ -0,0 +1,11 @@
+use aoc_common::Solution;
+use aoc_6::Day6;
+
+#[test]
+fn points_with_no_room_around_them() {
+    for input in ["0, 0\n-2147483648, 0\n", "0, 0\n0, 2147483647\n"] {
+        let e = Day6::parse(input.as_bytes()).err().expect("no room for the grid");
+        assert_eq!(e.location().map(|l| l.line), Some(2));
+    }
+    assert!(Day6::parse(&b"-2147483647, 2147483646\n-2147483640, 2147483640\n"[..]).is_ok());
+}
@@ -0,0 +1,11 @@
+use aoc_common::{Params, Solution};
+use aoc_common::testing::{assert_answer, example, example_params};
+use aoc_6::Day6;
+
+#[test]
+fn test1() {
+    let grid = example::<Day6>("tests/test1.txt");
+    let params = example_params("tests/config.toml");
+    assert_answer(Day6::part1(&grid, &Params::default()), 17);
+    assert_answer(Day6::part2(&grid, &params), 16);
+}
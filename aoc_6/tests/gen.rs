@@ -0,0 +1,8 @@
+use aoc_common::testing::check_generator;
+use aoc_6::Day6;
+use aoc_6::gen::GENERATOR;
+
+#[test]
+fn generated_inputs_parse_and_solve() {
+    check_generator::<Day6>(&GENERATOR, 20);
+}
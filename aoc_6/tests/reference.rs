@@ -0,0 +1,68 @@
+use std::collections::{HashMap, HashSet};
+use proptest::prelude::*;
+use aoc_common::{Params, Solution};
+use aoc_6::{region_size, Day6};
+
+/// The bounds of the points, expanded by one in every direction
+fn bounds(points: &[(i32, i32)]) -> ((i32, i32), (i32, i32)) {
+    let min_x = points.iter().map(|p| p.0).min().unwrap() - 1;
+    let min_y = points.iter().map(|p| p.1).min().unwrap() - 1;
+    let max_x = points.iter().map(|p| p.0).max().unwrap() + 1;
+    let max_y = points.iter().map(|p| p.1).max().unwrap() + 1;
+    ((min_x, min_y), (max_x, max_y))
+}
+
+fn distance(a: (i32, i32), b: (i32, i32)) -> i32 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+/// The only point nearest to the location, or `None` if several are equally near
+fn nearest(points: &[(i32, i32)], location: (i32, i32)) -> Option<usize> {
+    let min = points.iter().map(|&p| distance(p, location)).min().unwrap();
+    let mut nearest = points.iter().enumerate().filter(|(_, &p)| distance(p, location) == min).map(|(idx, _)| idx);
+    match (nearest.next(), nearest.next()) {
+        (Some(idx), None) => Some(idx),
+        _ => None,
+    }
+}
+
+fn reference_largest_area(points: &[(i32, i32)]) -> usize {
+    let ((min_x, min_y), (max_x, max_y)) = bounds(points);
+    let mut areas: HashMap<usize, usize> = HashMap::new();
+    let mut infinite = HashSet::new();
+    for x in min_x..=max_x {
+        for y in min_y..=max_y {
+            if let Some(idx) = nearest(points, (x, y)) {
+                *areas.entry(idx).or_insert(0) += 1;
+                if x == min_x || x == max_x || y == min_y || y == max_y { infinite.insert(idx); }
+            }
+        }
+    }
+    areas.iter().filter(|(idx, _)| !infinite.contains(idx)).map(|(_, &area)| area).max().unwrap_or(0)
+}
+
+/// Checks every location within `threshold` of the first point, as no location further from any
+/// point can have a total distance under it
+fn reference_region_size(points: &[(i32, i32)], threshold: usize) -> usize {
+    let ((x0, y0), radius) = (points[0], threshold as i32);
+    (x0 - radius..=x0 + radius).flat_map(|x| (y0 - radius..=y0 + radius).map(move |y| (x, y)))
+        .filter(|&location| points.iter().map(|&p| distance(p, location) as usize).sum::<usize>() < threshold)
+        .count()
+}
+
+proptest! {
+    #[test]
+    fn grid_matches_reference(points in prop::collection::vec((-20i32..20, -20i32..20), 1..12), threshold in 0usize..200) {
+        let input = points.iter().map(|p| format!("{}, {}\n", p.0, p.1)).collect::<String>();
+        let grid = Day6::parse(input.as_bytes()).unwrap();
+        prop_assert_eq!(Day6::part1(&grid, &Params::default()).unwrap().to_string(), reference_largest_area(&points).to_string());
+        prop_assert_eq!(region_size(&grid, threshold), reference_region_size(&points, threshold));
+    }
+}
+
+#[test]
+fn region_reaches_past_the_grid() {
+    // Every location within 4 steps of the only point: 1 + 4 + 8 + 12 + 16 of them
+    let grid = Day6::parse(&b"0, 0\n"[..]).unwrap();
+    assert_eq!(region_size(&grid, 5), 41);
+}
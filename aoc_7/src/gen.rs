@@ -0,0 +1,25 @@
+use std::io::Write;
+use rand::seq::SliceRandom;
+use aoc_common::gen::{GenRng, Generator};
+use aoc_common::{Error, Result};
+
+pub const GENERATOR: Generator = Generator { default_size: 100, unit: "dependencies", generate };
+
+/// Steps are single letters, so there can be at most this many distinct dependencies between them
+const MAX_DEPENDENCIES: usize = 26 * 25 / 2;
+
+/// Random dependencies between the steps A-Z. Each one points forward in a random ordering of
+/// the steps, so the graph never has a cycle.
+fn generate(rng: &mut GenRng, size: usize, out: &mut dyn Write) -> Result<()> {
+    if size > MAX_DEPENDENCIES {
+        return Err(Error::Other(format!("Steps are named A-Z, so there can be at most {} dependencies", MAX_DEPENDENCIES)));
+    }
+    let mut order = (b'A'..=b'Z').map(char::from).collect::<Vec<char>>();
+    order.shuffle(rng);
+    let mut pairs = (0..26).flat_map(|a| (a + 1..26).map(move |b| (a, b))).collect::<Vec<(usize, usize)>>();
+    pairs.shuffle(rng);
+    for &(a, b) in pairs.iter().take(size.max(1)) {
+        writeln!(out, "Step {} must be finished before step {} can begin.", order[a], order[b])?;
+    }
+    Ok(())
+}
@@ -0,0 +1,119 @@
+pub mod gen;
+
+use std::io::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tracing::{debug, trace};
+use aoc_common::{numbered_lines, Answer, Error, Params, Result, Solution};
+
+pub struct Dependencies {
+    steps_to_unlock: BTreeMap<char, usize>,
+    unlocks: HashMap<char, Vec<char>>
+}
+
+impl Dependencies {
+    pub fn parse<R: BufRead>(reader: R) -> Result<Dependencies> {
+        let mut ret = Dependencies {
+            steps_to_unlock: BTreeMap::new(),
+            unlocks: HashMap::new()
+        };
+
+        for l in numbered_lines(reader) {
+            let (line, text) = l?;
+            let trimmed = text.trim_start_matches("Step ").trim_end_matches(" can begin.");
+            let split = trimmed.splitn(2, " must be finished before step ").collect::<Vec<&str>>();
+            if split.len() != 2 || !split.iter().all(|c| c.len() == 1) {
+                return Err(Error::parse(line, &text, "Invalid format, expected \"Step <X> must be finished before step <Y> can begin.\""));
+            }
+            let (req, unlock) = (split[0].chars().next().unwrap(), split[1].chars().next().unwrap());
+            for (step, column) in [(req, 6), (unlock, 37)] {
+                if !step.is_ascii_uppercase() {
+                    return Err(Error::invalid(line, &text, "Steps must be named A-Z").at_column(column));
+                }
+            }
+
+            ret.steps_to_unlock.entry(req).or_insert(0);
+            *ret.steps_to_unlock.entry(unlock).or_insert(0) += 1;
+            ret.unlocks.entry(req).or_default().push(unlock);
+        }
+        Ok(ret)
+    }
+}
+
+/// Returns the order steps are started in and the total time taken to complete them all
+pub fn do_steps_with_workers(deps: &Dependencies, num_workers: usize, base_step_time: u32) -> (String, u32) {
+    let mut time_spent = 0;
+    let mut worker_time_until_free: Vec<u32> = vec![0; num_workers];
+    let mut in_progress: Vec<Option<char>> = vec![None; num_workers];
+    let mut unlocked: HashSet<char> = HashSet::new();
+    let mut sequence: Vec<char> = vec![];
+    let mut steps_left = deps.steps_to_unlock.clone();
+
+    while unlocked.len() < deps.steps_to_unlock.len() {
+        let min_time_until_worker_free = *worker_time_until_free.iter().filter(|&&t| t != 0).min().unwrap_or(&0);
+        time_spent += min_time_until_worker_free;
+
+        for (idx, until_free) in worker_time_until_free.iter_mut().enumerate() {
+            if *until_free <= min_time_until_worker_free {
+                *until_free = 0;
+                if let Some(finished) = in_progress[idx].take() {
+                    trace!(time = time_spent, worker = idx, step = %finished, "finished");
+                    for unlock in deps.unlocks.get(&finished).unwrap_or(&vec![]) {
+                        if let Some(to_dec) = steps_left.get_mut(unlock) { *to_dec -= 1; }
+                    }
+                }
+            } else {
+                *until_free -= min_time_until_worker_free;
+            }
+        }
+
+        for (idx, until_free) in worker_time_until_free.iter_mut().enumerate() {
+            if *until_free > 0 { continue }
+            let next = steps_left.iter().find(|(c, &r)| !unlocked.contains(c) && r == 0);
+            if let Some((&c, _)) = next {
+                sequence.push(c);
+                unlocked.insert(c);
+                *until_free = base_step_time + 1 + ((c as u8) - b'A') as u32;
+                in_progress[idx] = Some(c);
+            }
+        }
+        trace!(time = time_spent, workers = ?in_progress, until_free = ?worker_time_until_free, "tick");
+    }
+
+    (sequence.into_iter().collect::<String>(), time_spent + *worker_time_until_free.iter().max().unwrap())
+}
+
+/// The real puzzle's number of workers and time every step takes on top of its letter
+pub const WORKERS: usize = 5;
+pub const BASE_STEP_TIME: u32 = 60;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Dependencies;
+
+    const DAY: u8 = 7;
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "Step order",
+        "Total time spent on tasks with several workers",
+    ];
+
+    fn parse<R: BufRead>(reader: R) -> Result<Dependencies> {
+        Dependencies::parse(reader)
+    }
+
+    fn part1(deps: &Dependencies, _params: &Params) -> Result<Answer> {
+        Ok(do_steps_with_workers(deps, 1, 0).0.into())
+    }
+
+    fn part2(deps: &Dependencies, params: &Params) -> Result<Answer> {
+        let workers = params.workers.unwrap_or(WORKERS);
+        if workers == 0 { return Err(From::from("At least one worker is needed to do the steps")); }
+        let base_step_time = params.base_step_time.unwrap_or(BASE_STEP_TIME);
+        let (order, total_time) = do_steps_with_workers(deps, workers, base_step_time);
+        debug!(workers, base_step_time, %order, total_time);
+        Ok(Answer::from(total_time)
+            .with_detail("step_order", order)
+            .with_detail("workers", workers)
+            .with_detail("base_step_time", base_step_time))
+    }
+}
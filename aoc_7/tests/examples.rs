@@ -0,0 +1,11 @@
+use aoc_common::{Params, Solution};
+use aoc_common::testing::{assert_answer, example, example_params};
+use aoc_7::Day7;
+
+#[test]
+fn test1() {
+    let deps = example::<Day7>("tests/test1.txt");
+    let params = example_params("tests/config.toml");
+    assert_answer(Day7::part1(&deps, &Params::default()), "CABDFE");
+    assert_answer(Day7::part2(&deps, &params), 15);
+}
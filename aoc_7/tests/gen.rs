@@ -0,0 +1,8 @@
+use aoc_common::testing::check_generator;
+use aoc_7::Day7;
+use aoc_7::gen::GENERATOR;
+
+#[test]
+fn generated_inputs_parse_and_solve() {
+    check_generator::<Day7>(&GENERATOR, 50);
+}
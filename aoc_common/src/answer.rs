@@ -0,0 +1,97 @@
+use std::convert::TryFrom;
+use std::fmt;
+use serde_json::{json, Value};
+
+/// The result of one part of a day's puzzle
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    /// Several values, displayed comma-separated
+    Many(Vec<Answer>),
+    /// An answer along with named values that support it, such as the guard and minute
+    /// it was calculated from. Only the answer itself is displayed or verified; the runner
+    /// prints the rest after it.
+    Detailed(Box<Answer>, Vec<(&'static str, Answer)>),
+}
+
+impl Answer {
+    /// Attaches a named supporting value to the answer
+    pub fn with_detail<T: Into<Answer>>(self, name: &'static str, value: T) -> Answer {
+        match self {
+            Answer::Detailed(answer, mut details) => {
+                details.push((name, value.into()));
+                Answer::Detailed(answer, details)
+            },
+            answer => Answer::Detailed(Box::new(answer), vec![(name, value.into())]),
+        }
+    }
+
+    /// The supporting values attached with `with_detail`, in the order they were added
+    pub fn details(&self) -> &[(&'static str, Answer)] {
+        match self {
+            Answer::Detailed(_, details) => details,
+            _ => &[],
+        }
+    }
+
+    /// The answer as JSON, leaving out any supporting values
+    pub fn to_json(&self) -> Value {
+        match self {
+            Answer::Int(n) => json!(n),
+            Answer::Text(s) => json!(s),
+            Answer::Many(answers) => Value::Array(answers.iter().map(Answer::to_json).collect()),
+            Answer::Detailed(answer, _) => answer.to_json(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Many(answers) => {
+                for (idx, answer) in answers.iter().enumerate() {
+                    if idx > 0 { write!(f, ",")?; }
+                    write!(f, "{}", answer)?;
+                }
+                Ok(())
+            },
+            Answer::Detailed(answer, _) => write!(f, "{}", answer),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Answer { Answer::Int(n) }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Answer { Answer::Int(n as i64) }
+}
+
+impl From<i128> for Answer {
+    /// Too big for an `i64`, it's kept as text
+    fn from(n: i128) -> Answer { i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Int) }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer { i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Int) }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer { Answer::Int(n as i64) }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer { Answer::Text(s) }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer { Answer::Text(s.to_string()) }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(values: Vec<T>) -> Answer { Answer::Many(values.into_iter().map(Into::into).collect()) }
+}
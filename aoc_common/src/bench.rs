@@ -0,0 +1,71 @@
+use std::fs::OpenOptions;
+use std::io::prelude::*;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use crate::Result;
+
+/// Timing summary for one phase (parse, part 1 or part 2) over repeated runs
+#[derive(Clone, Debug)]
+pub struct Stats {
+    pub phase: &'static str,
+    pub runs: usize,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+/// Runs `f` `warmup` times untimed, then `runs` times timed
+pub fn measure<T, F: FnMut() -> Result<T>>(phase: &'static str, warmup: usize, runs: usize, mut f: F) -> Result<Stats> {
+    for _ in 0..warmup { f()?; }
+    let mut timings = Vec::with_capacity(runs);
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        f()?;
+        timings.push(start.elapsed());
+    }
+    timings.sort();
+    Ok(Stats {
+        phase,
+        runs: timings.len(),
+        median: timings[timings.len() / 2],
+        min: timings[0],
+        max: timings[timings.len() - 1],
+    })
+}
+
+pub fn print_stats(stats: &[Stats]) {
+    for s in stats {
+        println!(
+            "  {:<6} median {:>12?}  min {:>12?}  max {:>12?}  ({} runs)",
+            s.phase, s.median, s.min, s.max, s.runs
+        );
+    }
+}
+
+/// The current commit of the repository, or "unknown" outside of a git checkout
+pub fn git_commit() -> String {
+    Command::new("git").args(["rev-parse", "--short", "HEAD"]).output().ok()
+        .filter(|out| out.status.success())
+        .and_then(|out| String::from_utf8(out.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Appends one row per phase to a CSV file, writing the header if the file is new
+pub fn append_csv(path: &Path, day: u8, stats: &[Stats]) -> Result<()> {
+    let is_new = !path.exists();
+    let mut f = OpenOptions::new().create(true).append(true).open(path)?;
+    if is_new {
+        writeln!(f, "timestamp,commit,day,phase,runs,median_ns,min_ns,max_ns")?;
+    }
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let commit = git_commit();
+    for s in stats {
+        writeln!(
+            f, "{},{},{},{},{},{},{},{}",
+            timestamp, commit, day, s.phase, s.runs, s.median.as_nanos(), s.min.as_nanos(), s.max.as_nanos()
+        )?;
+    }
+    Ok(())
+}
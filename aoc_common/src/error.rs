@@ -0,0 +1,126 @@
+use std::fmt;
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+
+/// Where in a puzzle input something went wrong
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Location {
+    /// Filled in by the runner once it knows which input was being parsed
+    pub file: Option<PathBuf>,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, if the problem could be narrowed down to a single column
+    pub column: Option<usize>,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}", file.display(), self.line)?,
+            None => write!(f, "line {}", self.line)?,
+        }
+        if let Some(column) = self.column { write!(f, ":{}", column)?; }
+        Ok(())
+    }
+}
+
+pub enum Error {
+    /// A file couldn't be read
+    Io { file: Option<PathBuf>, source: io::Error },
+    /// A line of input doesn't have the expected format
+    Parse { location: Location, text: String, message: String },
+    /// A line of input is well-formed, but isn't valid for the puzzle
+    Invalid { location: Location, text: String, message: String },
+    /// Anything not tied to a line of input, e.g. a puzzle with no answer
+    Other(String),
+}
+
+impl Error {
+    pub fn parse<M: Into<String>>(line: usize, text: &str, message: M) -> Error {
+        Error::Parse { location: Location { line, ..Location::default() }, text: text.to_string(), message: message.into() }
+    }
+
+    pub fn invalid<M: Into<String>>(line: usize, text: &str, message: M) -> Error {
+        Error::Invalid { location: Location { line, ..Location::default() }, text: text.to_string(), message: message.into() }
+    }
+
+    /// Narrows a parse or validation error down to a 1-based column
+    pub fn at_column(mut self, column: usize) -> Error {
+        if let Error::Parse { location, .. } | Error::Invalid { location, .. } = &mut self {
+            location.column = Some(column);
+        }
+        self
+    }
+
+    /// Records which file the error came from, unless it already names one
+    pub fn in_file(mut self, path: &Path) -> Error {
+        match &mut self {
+            Error::Io { file, .. } | Error::Parse { location: Location { file, .. }, .. }
+            | Error::Invalid { location: Location { file, .. }, .. } => {
+                if file.is_none() { *file = Some(path.to_path_buf()); }
+            },
+            Error::Other(_) => (),
+        }
+        self
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Error::Parse { location, .. } | Error::Invalid { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { file: Some(file), source } => write!(f, "{}: {}", file.display(), source),
+            Error::Io { file: None, source } => write!(f, "{}", source),
+            Error::Parse { location, text, message } => write!(f, "{}: {}: {:?}", location, message, text),
+            Error::Invalid { location, text, message } => write!(f, "{}: invalid input, {}: {:?}", location, message, text),
+            Error::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+// `main` prints errors with `Debug`, so keep that readable too
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(source: io::Error) -> Error { Error::Io { file: None, source } }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Error { Error::Other(message) }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Error { Error::Other(message.to_string()) }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Error { Error::Other(e.to_string()) }
+}
+
+impl From<std::time::SystemTimeError> for Error {
+    fn from(e: std::time::SystemTimeError) -> Error { Error::Other(e.to_string()) }
+}
+
+/// The lines of an input, each paired with its 1-based line number
+pub fn numbered_lines<R: BufRead>(reader: R) -> impl Iterator<Item = Result<(usize, String), Error>> {
+    reader.lines().enumerate().map(|(idx, l)| Ok((idx + 1, l?)))
+}
@@ -0,0 +1,39 @@
+use std::fs;
+use std::path::Path;
+use crate::{Error, Result};
+
+/// Known-good answers for one input, read from an `answers.toml` such as:
+///
+/// ```toml
+/// part1 = 533
+/// part2 = "BKCJMSDVGHQRXFYZOAULPIEWTN"
+/// ```
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+    parts: [Option<String>; 2],
+}
+
+impl ExpectedAnswers {
+    pub fn load(path: &Path) -> Result<ExpectedAnswers> {
+        let contents = fs::read_to_string(path).map_err(|source| Error::Io { file: Some(path.to_path_buf()), source })?;
+        ExpectedAnswers::parse(&contents).map_err(|e| Error::Other(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn parse(contents: &str) -> Result<ExpectedAnswers> {
+        let table = contents.parse::<toml::Value>()?;
+        let mut ret = ExpectedAnswers::default();
+        for (idx, key) in ["part1", "part2"].iter().enumerate() {
+            ret.parts[idx] = match table.get(key) {
+                None => None,
+                Some(toml::Value::String(s)) => Some(s.clone()),
+                Some(toml::Value::Integer(n)) => Some(n.to_string()),
+                Some(other) => return Err(From::from(format!("Expected answer {} must be a string or integer, got {}", key, other))),
+            };
+        }
+        Ok(ret)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts[part as usize - 1].as_deref()
+    }
+}
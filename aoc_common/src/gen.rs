@@ -0,0 +1,23 @@
+//! Seeded random input generators, for stress-testing the days on inputs far larger than the official ones
+
+use std::io::Write;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use crate::Result;
+
+/// The generators' random number generator. It's seeded, so the same seed and size always give the same input.
+pub type GenRng = ChaCha8Rng;
+
+/// A day's input generator
+pub struct Generator {
+    /// The size used when none is given, roughly that of the official input
+    pub default_size: usize,
+    /// What the size counts, e.g. "frequency changes"
+    pub unit: &'static str,
+    /// Writes an input of the given size that the day's `parse` accepts
+    pub generate: fn(&mut GenRng, usize, &mut dyn Write) -> Result<()>,
+}
+
+pub fn rng(seed: u64) -> GenRng {
+    GenRng::seed_from_u64(seed)
+}
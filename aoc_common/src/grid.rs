@@ -0,0 +1,207 @@
+//! A dense 2D grid for the days that work on a map of the plane
+
+use std::convert::TryFrom;
+use std::io::{self, Write};
+use std::mem;
+use std::ops::{Index, IndexMut};
+use crate::{Error, Result};
+
+/// An `(x, y)` position on a grid, with `y` increasing downwards
+pub type Point = (i32, i32);
+
+/// A rectangle of cells stored row by row in one `Vec`. The top-left cell can be at any point,
+/// including negative ones, and cells are always addressed by their point rather than an index
+/// into the grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    origin: Point,
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Fails unless every cell of a `width` by `height` grid at `origin` has a point, and the cells
+/// can be allocated
+fn check_size<T>(origin: Point, width: usize, height: usize) -> Result<()> {
+    let fits = |start: i32, length: usize| {
+        length == 0 || i64::try_from(length - 1).is_ok_and(|last| start as i64 + last <= i32::MAX as i64)
+    };
+    let bytes = width.checked_mul(height).and_then(|cells| cells.checked_mul(mem::size_of::<T>()));
+    if !fits(origin.0, width) || !fits(origin.1, height) || bytes.is_none_or(|bytes| bytes > isize::MAX as usize) {
+        return Err(Error::Other(format!("A {}x{} grid with its top-left cell at {:?} is too big", width, height, origin)));
+    }
+    Ok(())
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with its top-left cell at `origin`, with every cell set to `value`.
+    /// Fails if the grid reaches past the largest point.
+    pub fn new(origin: Point, width: usize, height: usize, value: T) -> Result<Grid<T>> {
+        check_size::<T>(origin, width, height)?;
+        Ok(Grid { origin, width, height, cells: vec![value; width * height] })
+    }
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with its top-left cell at `origin`, with each cell set to `f` of
+    /// its point. Fails if the grid reaches past the largest point.
+    pub fn from_fn<F: FnMut(Point) -> T>(origin: Point, width: usize, height: usize, mut f: F) -> Result<Grid<T>> {
+        check_size::<T>(origin, width, height)?;
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (origin.0 + col as i32, origin.1 + row as i32)))
+            .map(&mut f)
+            .collect();
+        Ok(Grid { origin, width, height, cells })
+    }
+
+    /// The point of the top-left cell
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    /// The point of the bottom-right cell. Meaningless for an empty grid.
+    pub fn bottom_right(&self) -> Point {
+        (self.origin.0 + self.width as i32 - 1, self.origin.1 + self.height as i32 - 1)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, (x, y): Point) -> Option<usize> {
+        let (col, row) = (x as i64 - self.origin.0 as i64, y as i64 - self.origin.1 as i64);
+        if col < 0 || row < 0 || col >= self.width as i64 || row >= self.height as i64 { return None }
+        Some(row as usize * self.width + col as usize)
+    }
+
+    fn point_of(&self, idx: usize) -> Point {
+        (self.origin.0 + (idx % self.width) as i32, self.origin.1 + (idx / self.width) as i32)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    /// The cell at `point`, or `None` if it's outside the grid
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(move |idx| &mut self.cells[idx])
+    }
+
+    /// Every cell, row by row
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell with its point, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(move |(idx, cell)| (self.point_of(idx), cell))
+    }
+
+    /// The cells of row `y`, or `None` if it's outside the grid
+    pub fn row(&self, y: i32) -> Option<&[T]> {
+        let start = self.index_of((self.origin.0, y))?;
+        Some(&self.cells[start..start + self.width])
+    }
+
+    pub fn row_mut(&mut self, y: i32) -> Option<&mut [T]> {
+        let start = self.index_of((self.origin.0, y))?;
+        Some(&mut self.cells[start..start + self.width])
+    }
+
+    /// Every row, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() doesn't allow a size of 0, and a grid with no columns has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x` from top to bottom, which is empty if it's outside the grid
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        let start = self.index_of((x, self.origin.1));
+        let cells = match start {
+            Some(start) => &self.cells[start..],
+            None => &[],
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// Every cell on the edge of the grid with its point, each once, clockwise from the top-left
+    pub fn border(&self) -> impl Iterator<Item = (Point, &T)> {
+        let (left, top) = self.origin;
+        let (right, bottom) = self.bottom_right();
+        let points: Vec<Point> = if self.width == 0 || self.height == 0 {
+            vec![]
+        } else if self.width == 1 || self.height == 1 {
+            self.iter().map(|(point, _)| point).collect()
+        } else {
+            (left..right).map(|x| (x, top))
+                .chain((top..bottom).map(|y| (right, y)))
+                .chain((left + 1..=right).rev().map(|x| (x, bottom)))
+                .chain((top + 1..=bottom).rev().map(|y| (left, y)))
+                .collect()
+        };
+        points.into_iter().map(move |point| (point, &self[point]))
+    }
+
+    /// The up to four cells above, right of, below and left of `point` that are inside the grid
+    pub fn neighbours(&self, (x, y): Point) -> impl Iterator<Item = (Point, &T)> {
+        IntoIterator::into_iter([(0, -1), (1, 0), (0, 1), (-1, 0)])
+            .filter_map(move |(dx, dy)| Some((x.checked_add(dx)?, y.checked_add(dy)?)))
+            .filter_map(move |point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// The up to eight cells around `point`, including diagonally, that are inside the grid
+    pub fn all_neighbours(&self, (x, y): Point) -> impl Iterator<Item = (Point, &T)> {
+        (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(dx, dy)| Some((x.checked_add(dx)?, y.checked_add(dy)?)))
+            .filter_map(move |point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// The grid as text, one line per row with one character per cell
+    pub fn to_ascii<F: Fn(&T) -> char>(&self, f: F) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            out.extend(row.iter().map(&f));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Writes the grid as a binary PPM image, one pixel per cell coloured `f` of it
+    pub fn write_ppm<W: Write, F: Fn(&T) -> [u8; 3]>(&self, mut out: W, f: F) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        for cell in &self.cells {
+            out.write_all(&f(cell))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `point` is outside the grid
+    fn index(&self, point: Point) -> &T {
+        match self.index_of(point) {
+            Some(idx) => &self.cells[idx],
+            None => panic!("Point {:?} is outside the grid from {:?} to {:?}", point, self.origin, self.bottom_right()),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.index_of(point) {
+            Some(idx) => &mut self.cells[idx],
+            None => panic!("Point {:?} is outside the grid from {:?} to {:?}", point, self.origin, self.bottom_right()),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! The run history: every part the runner runs is appended to a JSON lines file, keyed by day,
+//! part, commit and a hash of the input, so answers and timings can be compared across commits
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use crate::{Error, Result};
+
+/// One part of one run
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    /// Unix time of the run
+    pub timestamp: u64,
+    pub commit: String,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub input_hash: String,
+    /// "debug" or "release", as timings are only comparable within the same one
+    pub profile: String,
+    pub answer: String,
+    /// How many timed runs the times are the median of, 1 if the run wasn't benchmarked
+    pub runs: usize,
+    /// Whether the times are benchmark medians rather than a single, possibly cold, run
+    #[serde(default)]
+    pub benchmarked: bool,
+    pub parse_ns: u64,
+    pub part_ns: u64,
+}
+
+impl Record {
+    /// A record of a part run now, stamped with the current commit and build profile
+    pub fn new(day: u8, part: u8, input: &Path, contents: &[u8], answer: String) -> Result<Record> {
+        Ok(Record {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            commit: crate::bench::git_commit(),
+            day,
+            part,
+            input: input.display().to_string(),
+            input_hash: hash_input(contents),
+            profile: if cfg!(debug_assertions) { "debug" } else { "release" }.to_string(),
+            answer,
+            runs: 1,
+            benchmarked: false,
+            parse_ns: 0,
+            part_ns: 0,
+        })
+    }
+
+    /// Whether `other` is a run of the same part on the same input, which should give the same answer
+    pub fn same_input(&self, other: &Record) -> bool {
+        (self.day, self.part, &self.input_hash) == (other.day, other.part, &other.input_hash)
+    }
+
+    /// Whether `other` is a run of the same part on the same input with comparable timings, as
+    /// they're only comparable within the same build profile and with or without benchmarking
+    pub fn same_series(&self, other: &Record) -> bool {
+        self.same_input(other) && (&self.profile, self.benchmarked) == (&other.profile, other.benchmarked)
+    }
+}
+
+/// Where the history is kept unless `--history` says otherwise: `.history.jsonl` in the workspace root
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(".history.jsonl")
+}
+
+/// A 64-bit FNV-1a hash of the input, in hex. Unlike std's hasher, it won't change between
+/// Rust versions, so old records still match.
+pub fn hash_input(contents: &[u8]) -> String {
+    let hash = contents.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &b| (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3));
+    format!("{:016x}", hash)
+}
+
+pub fn append(path: &Path, records: &[Record]) -> Result<()> {
+    let io_error = |source| Error::Io { file: Some(path.to_path_buf()), source };
+    let mut f = OpenOptions::new().create(true).append(true).open(path).map_err(io_error)?;
+    for record in records {
+        let line = serde_json::to_string(record).map_err(|e| Error::Other(e.to_string()))?;
+        writeln!(f, "{}", line).map_err(io_error)?;
+    }
+    Ok(())
+}
+
+/// Every record in the history, oldest first. A history that doesn't exist yet is empty.
+pub fn load(path: &Path) -> Result<Vec<Record>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(source) => return Err(Error::Io { file: Some(path.to_path_buf()), source }),
+    };
+    contents.lines().enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| serde_json::from_str(line).map_err(|e| Error::parse(idx + 1, line, e.to_string()).in_file(path)))
+        .collect()
+}
+
+/// Something worth a second look about a run, compared to the previous run of the same part on
+/// the same input
+#[derive(Clone, Debug, PartialEq)]
+pub enum Flag {
+    AnswerChanged { from: String },
+    /// `phase` is "parse" or "part"
+    Regressed { phase: &'static str, percent: f64, from: Duration },
+}
+
+impl Flag {
+    pub fn describe(&self) -> String {
+        match self {
+            Flag::AnswerChanged { from } => format!("answer changed from {}", from),
+            Flag::Regressed { phase, percent, from } => format!("{} regressed {:+.0}% from {:?}", phase, percent, from),
+        }
+    }
+}
+
+/// A run with the previous run in the same series, if there was one, and what changed since the
+/// previous runs
+#[derive(Clone, Debug)]
+pub struct Entry<'a> {
+    pub record: &'a Record,
+    pub previous: Option<&'a Record>,
+    pub flags: Vec<Flag>,
+}
+
+/// How much `time` changed from `previous`, as a percentage
+pub fn percent_change(time: u64, previous: u64) -> Option<f64> {
+    if previous == 0 { return None }
+    Some((time as f64 / previous as f64 - 1.0) * 100.0)
+}
+
+/// Flags each record whose answer differs from the previous run's on the same input, or whose
+/// parse or part time grew by more than `threshold` percent from the previous one of the same series
+pub fn analyse(records: &[Record], threshold: f64) -> Vec<Entry<'_>> {
+    records.iter().enumerate().map(|(idx, record)| {
+        let mut flags = vec![];
+        if let Some(previous) = records[..idx].iter().rev().find(|r| r.same_input(record)) {
+            if previous.answer != record.answer {
+                flags.push(Flag::AnswerChanged { from: previous.answer.clone() });
+            }
+        }
+        let previous = records[..idx].iter().rev().find(|r| r.same_series(record));
+        if let Some(previous) = previous {
+            for (phase, time, before) in [("parse", record.parse_ns, previous.parse_ns), ("part", record.part_ns, previous.part_ns)] {
+                match percent_change(time, before) {
+                    Some(percent) if percent > threshold => {
+                        flags.push(Flag::Regressed { phase, percent, from: Duration::from_nanos(before) });
+                    },
+                    _ => (),
+                }
+            }
+        }
+        Entry { record, previous, flags }
+    }).collect()
+}
@@ -0,0 +1,114 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use crate::{Error, Result};
+
+/// A puzzle input loaded into memory, so it can be parsed more than once
+#[derive(Debug)]
+pub struct PuzzleInput {
+    pub path: PathBuf,
+    pub contents: Vec<u8>,
+}
+
+impl PuzzleInput {
+    /// Reads the input from a file, or from stdin if the path is `-`
+    pub fn load(path: &Path) -> Result<PuzzleInput> {
+        if path == Path::new("-") {
+            let mut contents = vec![];
+            io::stdin().read_to_end(&mut contents).map_err(|source| Error::Io { file: None, source })?;
+            return Ok(PuzzleInput { path: PathBuf::from("<stdin>"), contents });
+        }
+        let contents = fs::read(path).map_err(|source| Error::Io { file: Some(path.to_path_buf()), source })?;
+        Ok(PuzzleInput { path: path.to_path_buf(), contents })
+    }
+
+    pub fn reader(&self) -> &[u8] {
+        &self.contents
+    }
+
+    /// Strips a UTF-8 BOM, turns CRLF and lone CR line endings into LF, and trims trailing
+    /// whitespace from each line and blank lines from the end. The result has no final newline, so
+    /// days that read the whole input as one string don't see one either.
+    pub fn normalize(&mut self) -> Normalized {
+        let mut normalized = Normalized::default();
+        let mut contents = &self.contents[..];
+        if contents.starts_with(BOM) {
+            contents = &contents[BOM.len()..];
+            normalized.bom = true;
+        }
+
+        let mut lines = vec![];
+        let mut start = 0;
+        let mut idx = 0;
+        while idx < contents.len() {
+            match contents[idx] {
+                b'\n' => {
+                    lines.push(&contents[start..idx]);
+                    start = idx + 1;
+                },
+                b'\r' => {
+                    lines.push(&contents[start..idx]);
+                    if contents.get(idx + 1) == Some(&b'\n') { idx += 1; }
+                    start = idx + 1;
+                    normalized.line_endings += 1;
+                },
+                _ => (),
+            }
+            idx += 1;
+        }
+        // A final line ending doesn't start another line
+        if start < contents.len() { lines.push(&contents[start..]); }
+
+        let mut lines = lines.into_iter().map(|line| {
+            let trimmed = line.trim_ascii_end();
+            if trimmed.len() < line.len() { normalized.trimmed_lines += 1; }
+            trimmed
+        }).collect::<Vec<&[u8]>>();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+            normalized.blank_lines += 1;
+        }
+
+        self.contents = lines.join(&b'\n');
+        normalized
+    }
+}
+
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// What `PuzzleInput::normalize` had to change, to warn about
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Normalized {
+    pub bom: bool,
+    /// CRLF or lone CR line endings turned into LFs
+    pub line_endings: usize,
+    /// Lines that had trailing whitespace
+    pub trimmed_lines: usize,
+    /// Blank lines removed from the end
+    pub blank_lines: usize,
+}
+
+impl Normalized {
+    pub fn is_empty(&self) -> bool {
+        *self == Normalized::default()
+    }
+}
+
+impl fmt::Display for Normalized {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let plural = |n: usize| if n == 1 { "" } else { "s" };
+        let mut changes = vec![];
+        if self.bom { changes.push("stripped a UTF-8 BOM".to_string()); }
+        if self.line_endings > 0 {
+            changes.push(format!("converted {} CRLF or CR line ending{} to LF", self.line_endings, plural(self.line_endings)));
+        }
+        if self.trimmed_lines > 0 {
+            changes.push(format!("trimmed trailing whitespace from {} line{}", self.trimmed_lines, plural(self.trimmed_lines)));
+        }
+        if self.blank_lines > 0 {
+            changes.push(format!("removed {} trailing blank line{}", self.blank_lines, plural(self.blank_lines)));
+        }
+        write!(f, "{}", changes.join(", "))
+    }
+}
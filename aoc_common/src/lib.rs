@@ -0,0 +1,369 @@
+mod answer;
+pub mod bench;
+mod error;
+mod expected;
+pub mod gen;
+mod grid;
+pub mod history;
+mod input;
+mod params;
+pub mod testing;
+
+use std::io::{self, BufRead, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use serde_json::{json, Map, Value};
+use structopt::StructOpt;
+
+pub use answer::Answer;
+pub use error::{numbered_lines, Error, Location};
+pub use expected::ExpectedAnswers;
+pub use grid::{Grid, Point};
+pub use input::{Normalized, PuzzleInput};
+pub use params::Params;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Parses a day's input, then runs, prints and optionally verifies or benchmarks the selected parts
+pub type RunFn = fn(&PuzzleInput, &RunOptions) -> Result<()>;
+
+/// A day's puzzle, split into parsing and the two parts that answer it
+pub trait Solution {
+    type Input;
+
+    const DAY: u8;
+    /// What each part's answer means, printed alongside it
+    const DESCRIPTIONS: [&'static str; 2];
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input>;
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer>;
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer>;
+}
+
+/// How answers are printed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line for each part run
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Format> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(From::from(format!("Invalid format: {} (expected text or json)", s))),
+        }
+    }
+}
+
+// Options shared by every day's binary and the runner. Not a doc comment, as structopt would
+// use it as the help text of every command that flattens these in.
+#[derive(Debug, Default, StructOpt)]
+pub struct RunOptions {
+    /// Only run this part (1 or 2) instead of both
+    #[structopt(short = "p", long = "part", parse(try_from_str = parse_part))]
+    pub part: Option<u8>,
+    /// Check the answers against the expected ones and fail on any mismatch
+    #[structopt(long = "verify")]
+    pub verify: bool,
+    /// Expected answers to verify against, defaults to answers.toml next to the input
+    #[structopt(long = "answers", parse(from_os_str), requires = "verify")]
+    pub answers: Option<PathBuf>,
+    /// Time parsing and each part separately over repeated runs
+    #[structopt(long = "bench")]
+    pub bench: bool,
+    /// Untimed runs of each phase before benchmarking it
+    #[structopt(long = "warmup", default_value = "3")]
+    pub warmup: usize,
+    /// Timed runs of each phase when benchmarking
+    #[structopt(long = "runs", default_value = "10")]
+    pub runs: usize,
+    /// Append the benchmark results to this CSV file
+    #[structopt(long = "bench-csv", parse(from_os_str), requires = "bench")]
+    pub bench_csv: Option<PathBuf>,
+    /// Print answers as text, or as one JSON object per part
+    #[structopt(long = "format", default_value = "text", possible_values = &["text", "json"])]
+    pub format: Format,
+    /// Puzzle parameters, defaults to config.toml next to the input if there is one
+    #[structopt(long = "config", parse(from_os_str))]
+    pub config: Option<PathBuf>,
+    /// Record the runs in this file instead of .history.jsonl in the workspace root
+    #[structopt(long = "history", parse(from_os_str))]
+    pub history: Option<PathBuf>,
+    /// Don't record the runs in the history
+    #[structopt(long = "no-history", conflicts_with = "history")]
+    pub no_history: bool,
+    /// Parse the input exactly as it is, without stripping a BOM, converting line endings or
+    /// trimming trailing whitespace and blank lines
+    #[structopt(long = "raw")]
+    pub raw: bool,
+    /// Log how each answer was reached to stderr. Takes an optional filter like aoc_7=trace or
+    /// aoc_4=debug, and logs everything without one.
+    #[structopt(long = "trace")]
+    pub trace: Option<Option<String>>,
+    #[structopt(flatten)]
+    pub params: Params,
+}
+
+impl RunOptions {
+    fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+#[derive(Debug, StructOpt)]
+pub struct Cli {
+    /// Input file, or - for stdin. Can be given more than once.
+    #[structopt(short = "f", parse(from_os_str), required = true, number_of_values = 1)]
+    pub files: Vec<PathBuf>,
+    #[structopt(flatten)]
+    pub opts: RunOptions,
+}
+
+/// Where the expected answers for the given input live by default
+pub fn answers_path(input: &Path) -> PathBuf {
+    input.with_file_name("answers.toml")
+}
+
+/// Where the puzzle parameters for the given input live by default
+pub fn config_path(input: &Path) -> PathBuf {
+    input.with_file_name("config.toml")
+}
+
+/// The parameters given on the command line, with the rest from the config file
+fn load_params(input: &Path, opts: &RunOptions) -> Result<Params> {
+    let file_params = match &opts.config {
+        Some(path) => Params::load(path)?,
+        None if config_path(input).exists() => Params::load(&config_path(input))?,
+        None => Params::default(),
+    };
+    Ok(opts.params.clone().or(file_params))
+}
+
+pub fn parse_part(s: &str) -> Result<u8> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(From::from(format!("Invalid part: {} (expected 1 or 2)", s))),
+    }
+}
+
+fn run_part<S: Solution>(input: &S::Input, params: &Params, part: u8) -> Result<Answer> {
+    if part == 1 { S::part1(input, params) } else { S::part2(input, params) }
+}
+
+/// How one part's answer compared against the expected answers
+enum Status {
+    Ok,
+    Mismatch(String),
+    Missing,
+}
+
+impl Status {
+    fn describe(&self) -> String {
+        match self {
+            Status::Ok => "ok".to_string(),
+            Status::Mismatch(expected) => format!("expected {}", expected),
+            Status::Missing => "no expected answer".to_string(),
+        }
+    }
+}
+
+fn stats_json(stats: &bench::Stats) -> Value {
+    json!({
+        "runs": stats.runs,
+        "median_ns": stats.median.as_nanos() as u64,
+        "min_ns": stats.min.as_nanos() as u64,
+        "max_ns": stats.max.as_nanos() as u64,
+    })
+}
+
+/// One part's result as a JSON object, with the benchmark stats for parsing and the part if there are any
+fn part_json<S: Solution>(input: &Path, part: u8, answer: &Answer, status: Option<&Status>, timing: (Duration, Duration), stats: &[bench::Stats]) -> Value {
+    let mut obj = Map::new();
+    obj.insert("day".to_string(), json!(S::DAY));
+    obj.insert("input".to_string(), json!(input.display().to_string()));
+    obj.insert("part".to_string(), json!(part));
+    obj.insert("description".to_string(), json!(S::DESCRIPTIONS[part as usize - 1]));
+    obj.insert("answer".to_string(), answer.to_json());
+    let details = answer.details().iter().map(|(name, value)| (name.to_string(), value.to_json())).collect::<Map<_, _>>();
+    obj.insert("details".to_string(), Value::Object(details));
+    if let Some(status) = status {
+        let (status, expected) = match status {
+            Status::Ok => ("ok", None),
+            Status::Mismatch(expected) => ("mismatch", Some(expected)),
+            Status::Missing => ("missing", None),
+        };
+        obj.insert("status".to_string(), json!(status));
+        if let Some(expected) = expected { obj.insert("expected".to_string(), json!(expected)); }
+    }
+    obj.insert("timing".to_string(), json!({
+        "parse_ns": timing.0.as_nanos() as u64,
+        "part_ns": timing.1.as_nanos() as u64,
+    }));
+    let phase = if part == 1 { "part1" } else { "part2" };
+    let bench = stats.iter().filter(|s| s.phase == "parse" || s.phase == phase)
+        .map(|s| (if s.phase == "parse" { "parse" } else { "part" }.to_string(), stats_json(s)))
+        .collect::<Map<_, _>>();
+    if !bench.is_empty() { obj.insert("bench".to_string(), Value::Object(bench)); }
+    Value::Object(obj)
+}
+
+/// Parses the input, then runs and prints the selected parts. When verifying,
+/// every part is still run and printed before any mismatch is reported.
+pub fn run<S: Solution>(puzzle_input: &PuzzleInput, opts: &RunOptions) -> Result<()> {
+    let expected = if opts.verify {
+        let path = opts.answers.clone().unwrap_or_else(|| answers_path(&puzzle_input.path));
+        Some(ExpectedAnswers::load(&path)?)
+    } else {
+        None
+    };
+    let params = load_params(&puzzle_input.path, opts)?;
+    // Lets --trace filter by day, and shows which part each log line came from
+    let _day = tracing::info_span!("day", day = S::DAY).entered();
+    let start = Instant::now();
+    let input = tracing::info_span!("parse").in_scope(|| S::parse(puzzle_input.reader())).map_err(|e| e.in_file(&puzzle_input.path))?;
+    let parse_time = start.elapsed();
+
+    let mut results = vec![];
+    for part in (1..=2).filter(|&p| opts.runs_part(p)) {
+        let start = Instant::now();
+        let answer = tracing::info_span!("part", part).in_scope(|| run_part::<S>(&input, &params, part))
+            .map_err(|e| e.in_file(&puzzle_input.path))?;
+        let part_time = start.elapsed();
+        let status = expected.as_ref().map(|e| match e.get(part) {
+            None => Status::Missing,
+            Some(e) if e == answer.to_string() => Status::Ok,
+            Some(e) => Status::Mismatch(e.to_string()),
+        });
+        if opts.format == Format::Text {
+            print_answer(S::DESCRIPTIONS[part as usize - 1], &answer, status.as_ref().map(Status::describe).as_deref());
+        }
+        results.push((part, answer, status, part_time));
+    }
+
+    let mut stats = vec![];
+    if opts.bench {
+        // The answers have already been traced once, and logging would skew the timings
+        tracing::dispatcher::with_default(&tracing::Dispatch::none(), || -> Result<()> {
+            stats.push(bench::measure("parse", opts.warmup, opts.runs, || S::parse(puzzle_input.reader()))?);
+            for (part, phase) in [(1, "part1"), (2, "part2")] {
+                if !opts.runs_part(part) { continue }
+                stats.push(bench::measure(phase, opts.warmup, opts.runs, || run_part::<S>(&input, &params, part))?);
+            }
+            Ok(())
+        })?;
+        if opts.format == Format::Text { bench::print_stats(&stats); }
+        if let Some(path) = &opts.bench_csv {
+            bench::append_csv(path, S::DAY, &stats)?;
+        }
+    }
+
+    if !opts.no_history {
+        let median = |phase: &str| stats.iter().find(|s| s.phase == phase).map(|s| (s.runs, s.median));
+        let records = results.iter().map(|(part, answer, _, part_time)| {
+            let mut record = history::Record::new(S::DAY, *part, &puzzle_input.path, &puzzle_input.contents, answer.to_string())?;
+            // Benchmarked times are steadier than the single run's, so prefer them when there are some
+            let (runs, parse_time) = median("parse").unwrap_or((1, parse_time));
+            let part_time = median(if *part == 1 { "part1" } else { "part2" }).map_or(*part_time, |(_, t)| t);
+            record.runs = runs;
+            record.benchmarked = opts.bench;
+            record.parse_ns = parse_time.as_nanos() as u64;
+            record.part_ns = part_time.as_nanos() as u64;
+            Ok(record)
+        }).collect::<Result<Vec<_>>>()?;
+        history::append(&opts.history.clone().unwrap_or_else(history::default_path), &records)?;
+    }
+
+    if opts.format == Format::Json {
+        for (part, answer, status, part_time) in &results {
+            println!("{}", part_json::<S>(&puzzle_input.path, *part, answer, status.as_ref(), (parse_time, *part_time), &stats));
+        }
+    }
+
+    let mismatches = results.iter()
+        .filter(|(_, _, status, _)| matches!(status, Some(Status::Mismatch(_))))
+        .map(|(part, _, _, _)| part.to_string())
+        .collect::<Vec<_>>();
+    if !mismatches.is_empty() {
+        return Err(From::from(format!("Answer mismatch in part {}", mismatches.join(" and "))));
+    }
+    Ok(())
+}
+
+/// Loads an input and, unless `--raw` was given, normalizes it, warning about anything that changed
+pub fn load_input(path: &Path, opts: &RunOptions) -> Result<PuzzleInput> {
+    let mut input = PuzzleInput::load(path)?;
+    if !opts.raw {
+        let normalized = input.normalize();
+        if !normalized.is_empty() {
+            eprintln!("warning: {}: {} (pass --raw to keep the input as it is)", input.path.display(), normalized);
+        }
+    }
+    Ok(input)
+}
+
+/// Sends the days' logs to stderr, filtered by `filter`, which takes `RUST_LOG`-style directives,
+/// e.g. `aoc_4=debug,aoc_7::steps=trace` or `[day{day=6}]=debug`. Only the first call in a process
+/// has any effect.
+pub fn init_tracing(filter: &str) -> Result<()> {
+    let mut env_filter = tracing_subscriber::EnvFilter::try_new(filter).map_err(|e| format!("Invalid --trace filter {:?}: {}", filter, e))?;
+    // The day and part spans come from here, so keep them unless the filter says otherwise, or a
+    // filter for one day would lose which part each line came from
+    if !filter.contains("aoc_common") { env_filter = env_filter.add_directive("aoc_common=info".parse().unwrap()); }
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(env_filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .try_init();
+    Ok(())
+}
+
+/// Runs a day on each of the inputs in turn, printing a header before each one's
+/// results when there's more than one. A failing input doesn't stop the rest.
+pub fn run_inputs(run: RunFn, paths: &[PathBuf], opts: &RunOptions) -> Result<()> {
+    if let Some(filter) = &opts.trace { init_tracing(filter.as_deref().unwrap_or("trace"))?; }
+    if paths.len() == 1 {
+        return run(&load_input(&paths[0], opts)?, opts);
+    }
+    let mut failed = vec![];
+    for path in paths {
+        if opts.format == Format::Text { println!("==> {} <==", path.display()); }
+        if let Err(e) = load_input(path, opts).and_then(|input| run(&input, opts)) {
+            match opts.format {
+                Format::Text => println!("{} failed: {}", path.display(), e),
+                Format::Json => eprintln!("{} failed: {}", path.display(), e),
+            }
+            failed.push(path.display().to_string());
+        }
+    }
+    if !failed.is_empty() {
+        return Err(From::from(format!("Failed inputs: {}", failed.join(", "))));
+    }
+    Ok(())
+}
+
+/// Entry point shared by every day's standalone binary
+pub fn main<S: Solution>() -> Result<()> {
+    let opt = Cli::from_args();
+    run_inputs(run::<S>, &opt.files, &opt.opts)
+}
+
+/// Prints an answer, followed by any supporting values on an indented line
+pub fn print_answer(description: &str, answer: &Answer, status: Option<&str>) {
+    match status {
+        Some(status) => println!("{}: {} [{}]", description, answer, status),
+        None => println!("{}: {}", description, answer),
+    }
+    if !answer.details().is_empty() {
+        let details = answer.details().iter().map(|(name, value)| format!("{}: {}", name, value)).collect::<Vec<_>>();
+        println!("  {}", details.join(", "));
+    }
+}
@@ -0,0 +1,66 @@
+//! Puzzle parameters that would otherwise be compiled in, for running the examples and variant
+//! inputs. Each day reads the ones it uses and falls back to the real puzzle's value for any
+//! left unset. They can also be read from a per-day `config.toml` with the same names, e.g.
+//!
+//! ```toml
+//! workers = 2
+//! base-step-time = 0
+//! ```
+
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use structopt::StructOpt;
+use crate::{Error, Result};
+
+#[derive(Clone, Debug, Default, Deserialize, StructOpt)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Params {
+    /// Letters box IDs and polymer units are made of (days 2 and 5, default a-z)
+    #[structopt(long = "alphabet")]
+    pub alphabet: Option<String>,
+    /// Width and height of the fabric (day 3, default 1000)
+    #[structopt(long = "fabric-size")]
+    pub fabric_size: Option<usize>,
+    /// Total distance locations in the region must be under (day 6, default 10000)
+    #[structopt(long = "distance-threshold")]
+    pub distance_threshold: Option<usize>,
+    /// Number of workers doing steps in part 2 (day 7, default 5)
+    #[structopt(long = "workers")]
+    pub workers: Option<usize>,
+    /// Time every step takes on top of its letter's position in the alphabet (day 7, default 60)
+    #[structopt(long = "base-step-time")]
+    pub base_step_time: Option<u32>,
+    /// Keep frequencies in 128-bit integers, for inputs that overflow 64 bits (day 1)
+    #[structopt(long = "wide")]
+    #[serde(default)]
+    pub wide: bool,
+}
+
+impl Params {
+    pub fn load(path: &Path) -> Result<Params> {
+        let contents = fs::read_to_string(path).map_err(|source| Error::Io { file: Some(path.to_path_buf()), source })?;
+        toml::from_str(&contents).map_err(|e| Error::Other(format!("{}: {}", path.display(), e)))
+    }
+
+    /// These parameters, with any that are unset taken from `fallback`
+    pub fn or(self, fallback: Params) -> Params {
+        Params {
+            alphabet: self.alphabet.or(fallback.alphabet),
+            fabric_size: self.fabric_size.or(fallback.fabric_size),
+            distance_threshold: self.distance_threshold.or(fallback.distance_threshold),
+            workers: self.workers.or(fallback.workers),
+            base_step_time: self.base_step_time.or(fallback.base_step_time),
+            wide: self.wide || fallback.wide,
+        }
+    }
+
+    /// The alphabet, or a-z if it's unset
+    pub fn alphabet(&self) -> Result<Vec<char>> {
+        match &self.alphabet {
+            Some(alphabet) if alphabet.is_empty() => Err(From::from("The alphabet can't be empty")),
+            Some(alphabet) => Ok(alphabet.chars().collect()),
+            None => Ok(('a'..='z').collect()),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Helpers for checking each day against the examples in its `tests/` directory
+
+use std::fmt::Display;
+use std::path::Path;
+use crate::{Answer, Params, PuzzleInput, Result, Solution};
+use crate::gen::{self, Generator};
+
+/// Parses an example input, relative to the root of the crate under test, normalized like the
+/// runner normalizes inputs
+pub fn example<S: Solution>(path: &str) -> S::Input {
+    let mut input = PuzzleInput::load(Path::new(path)).unwrap_or_else(|e| panic!("{}", e));
+    input.normalize();
+    S::parse(input.reader()).map_err(|e| e.in_file(&input.path)).unwrap_or_else(|e| panic!("Couldn't parse example: {}", e))
+}
+
+/// Loads the parameters an example needs, relative to the root of the crate under test
+pub fn example_params(path: &str) -> Params {
+    Params::load(Path::new(path)).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Asserts that a part succeeded with the expected answer
+pub fn assert_answer<T: Display>(answer: Result<Answer>, expected: T) {
+    match answer {
+        Ok(answer) => assert_eq!(answer.to_string(), expected.to_string()),
+        Err(e) => panic!("Expected answer {}, got error: {}", expected, e),
+    }
+}
+
+/// Checks that a day's generator gives inputs its `parse` accepts and both parts can answer,
+/// over a few seeds at the given (small) size
+pub fn check_generator<S: Solution>(generator: &Generator, size: usize) {
+    let params = Params::default();
+    for seed in 0..5 {
+        let mut contents = vec![];
+        (generator.generate)(&mut gen::rng(seed), size, &mut contents).unwrap_or_else(|e| panic!("Seed {}: {}", seed, e));
+        let input = S::parse(&contents[..]).unwrap_or_else(|e| panic!("Seed {}: couldn't parse generated input: {}", seed, e));
+        for part in 1..=2 {
+            if let Err(e) = if part == 1 { S::part1(&input, &params) } else { S::part2(&input, &params) } {
+                panic!("Seed {}: part {} failed on generated input: {}", seed, part, e);
+            }
+        }
+    }
+}
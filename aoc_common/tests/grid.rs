@@ -0,0 +1,67 @@
+use aoc_common::Grid;
+
+/// A 3x2 grid with its origin at (-1, -1), each cell holding its own point
+fn small_grid() -> Grid<(i32, i32)> {
+    Grid::from_fn((-1, -1), 3, 2, |point| point).unwrap()
+}
+
+#[test]
+fn get_is_bounds_checked() {
+    let grid = small_grid();
+    assert_eq!(grid.get((-1, -1)), Some(&(-1, -1)));
+    assert_eq!(grid.get((1, 0)), Some(&(1, 0)));
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.get((-2, 0)), None);
+    assert_eq!(grid.get((0, 1)), None);
+    assert_eq!(grid.bottom_right(), (1, 0));
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = small_grid();
+    assert_eq!(grid.row(0), Some(&[(-1, 0), (0, 0), (1, 0)][..]));
+    assert_eq!(grid.row(1), None);
+    assert_eq!(grid.column(0).collect::<Vec<_>>(), vec![&(0, -1), &(0, 0)]);
+    assert_eq!(grid.column(5).count(), 0);
+    assert_eq!(grid.rows().count(), 2);
+}
+
+#[test]
+fn border_visits_each_edge_cell_once() {
+    let grid = Grid::from_fn((0, 0), 4, 3, |point| point).unwrap();
+    let border = grid.border().map(|(point, _)| point).collect::<Vec<_>>();
+    assert_eq!(border, vec![(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (3, 2), (2, 2), (1, 2), (0, 2), (0, 1)]);
+    assert_eq!(small_grid().border().count(), 6);
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = small_grid();
+    let neighbours = grid.neighbours((-1, -1)).map(|(point, _)| point).collect::<Vec<_>>();
+    assert_eq!(neighbours, vec![(0, -1), (-1, 0)]);
+    assert_eq!(grid.all_neighbours((0, 0)).count(), 5);
+}
+
+#[test]
+fn dumps() {
+    let mut grid = Grid::new((-2, 5), 3, 2, false).unwrap();
+    grid[(-1, 6)] = true;
+    assert_eq!(grid.to_ascii(|&set| if set { '#' } else { '.' }), "...\n.#.\n");
+
+    let mut ppm = vec![];
+    grid.write_ppm(&mut ppm, |&set| if set { [255, 255, 255] } else { [0, 0, 0] }).unwrap();
+    assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+    assert_eq!(ppm.len(), b"P6\n3 2\n255\n".len() + 3 * 2 * 3);
+}
+
+#[test]
+fn rejects_grids_past_the_largest_point() {
+    assert!(Grid::new((i32::MAX - 2, 0), 3, 1, 0u8).is_ok());
+    assert!(Grid::new((i32::MAX - 2, 0), 4, 1, 0u8).is_err());
+    assert!(Grid::from_fn((0, i32::MIN), 1, 1 << 33, |point| point).is_err());
+    assert!(Grid::new((0, 0), 1 << 31, 1 << 31, 0u64).is_err());
+    // Neighbours past the largest point aren't in the grid
+    let grid = Grid::new((i32::MAX, i32::MAX), 1, 1, 0u8).unwrap();
+    assert_eq!(grid.neighbours((i32::MAX, i32::MAX)).count(), 0);
+    assert_eq!(grid.all_neighbours((i32::MAX, i32::MAX)).count(), 0);
+}
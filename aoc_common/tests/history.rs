@@ -0,0 +1,73 @@
+use std::path::Path;
+use aoc_common::history::{self, Flag, Record};
+
+fn record(commit: &str, answer: &str, parse_ns: u64, part_ns: u64) -> Record {
+    let mut record = Record::new(6, 1, Path::new("in.txt"), b"1, 1\n", answer.to_string()).unwrap();
+    record.commit = commit.to_string();
+    record.parse_ns = parse_ns;
+    record.part_ns = part_ns;
+    record
+}
+
+#[test]
+fn hash_is_stable() {
+    assert_eq!(history::hash_input(b""), "cbf29ce484222325");
+    assert_eq!(history::hash_input(b"a"), "af63dc4c8601ec8c");
+}
+
+#[test]
+fn appends_and_loads() {
+    let path = std::env::temp_dir().join(format!("aoc_history_{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    assert!(history::load(&path).unwrap().is_empty());
+    let records = vec![record("a", "17", 100, 200), record("b", "17", 100, 210)];
+    history::append(&path, &records[..1]).unwrap();
+    history::append(&path, &records[1..]).unwrap();
+    assert_eq!(history::load(&path).unwrap(), records);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn flags_changed_answers_and_regressions() {
+    let mut other_input = record("b", "99", 100, 900);
+    other_input.input_hash = "different".to_string();
+    let records = vec![
+        record("a", "17", 100, 200),
+        other_input,
+        record("b", "17", 150, 210),
+        record("c", "18", 150, 210),
+    ];
+    let entries = history::analyse(&records, 20.0);
+    assert!(entries[0].flags.is_empty() && entries[0].previous.is_none());
+    // A different input is its own series, so it isn't compared with anything
+    assert!(entries[1].flags.is_empty());
+    // 5% slower is within the threshold, 50% isn't
+    assert_eq!(entries[2].previous.unwrap().commit, "a");
+    assert!(matches!(&entries[2].flags[..], [Flag::Regressed { phase: "parse", .. }]));
+    assert_eq!(entries[3].flags, [Flag::AnswerChanged { from: "17".to_string() }]);
+}
+
+#[test]
+fn benchmarks_are_only_compared_with_benchmarks() {
+    // Even a benchmark of a single run is kept apart from plain runs
+    let mut bench = record("a", "17", 100, 200);
+    bench.runs = 1;
+    bench.benchmarked = true;
+    let records = vec![bench.clone(), record("b", "17", 300, 600), bench];
+    let entries = history::analyse(&records, 20.0);
+    // A single cold run is its own series, rather than a regression from the benchmark's medians
+    assert!(entries[1].previous.is_none() && entries[1].flags.is_empty());
+    assert_eq!(entries[2].previous.unwrap().commit, "a");
+}
+
+#[test]
+fn answers_are_compared_across_series() {
+    let mut bench = record("a", "17", 100, 200);
+    bench.benchmarked = true;
+    let mut release = record("c", "18", 100, 200);
+    release.profile = "release".to_string();
+    let records = vec![bench, record("b", "18", 300, 600), release];
+    let entries = history::analyse(&records, 20.0);
+    assert_eq!(entries[1].flags, [Flag::AnswerChanged { from: "17".to_string() }]);
+    assert!(entries[2].previous.is_none() && entries[2].flags.is_empty());
+}
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+use aoc_common::{Normalized, PuzzleInput};
+
+fn normalize(contents: &[u8]) -> (String, Normalized) {
+    let mut input = PuzzleInput { path: PathBuf::from("in.txt"), contents: contents.to_vec() };
+    let normalized = input.normalize();
+    (String::from_utf8(input.contents).unwrap(), normalized)
+}
+
+#[test]
+fn final_newline_is_dropped_quietly() {
+    assert_eq!(normalize(b"+1\n-2\n"), ("+1\n-2".to_string(), Normalized::default()));
+    assert_eq!(normalize(b"dabAcCaCBAcCcaDA"), ("dabAcCaCBAcCcaDA".to_string(), Normalized::default()));
+    assert_eq!(normalize(b""), (String::new(), Normalized::default()));
+}
+
+#[test]
+fn strips_bom_and_converts_line_endings() {
+    let (contents, normalized) = normalize(b"\xef\xbb\xbfStep A must be finished before step B can begin.\r\n+1\r-2\r\n");
+    assert_eq!(contents, "Step A must be finished before step B can begin.\n+1\n-2");
+    assert_eq!(normalized, Normalized { bom: true, line_endings: 3, ..Normalized::default() });
+    assert_eq!(normalized.to_string(), "stripped a UTF-8 BOM, converted 3 CRLF or CR line endings to LF");
+}
+
+#[test]
+fn trims_trailing_whitespace_and_blank_lines() {
+    let (contents, normalized) = normalize(b"  1, 1 \n2, 2\t\n\n \n\n");
+    assert_eq!(contents, "  1, 1\n2, 2");
+    assert_eq!(normalized, Normalized { trimmed_lines: 3, blank_lines: 3, ..Normalized::default() });
+    assert_eq!(normalized.to_string(), "trimmed trailing whitespace from 3 lines, removed 3 trailing blank lines");
+}
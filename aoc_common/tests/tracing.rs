@@ -0,0 +1,7 @@
+use aoc_common::init_tracing;
+
+#[test]
+fn rejects_invalid_filters() {
+    assert!(init_tracing("aoc_1=[[").is_err());
+    assert!(init_tracing("aoc_4=debug,[day{day=6}]=trace").is_ok());
+}